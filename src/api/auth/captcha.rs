@@ -0,0 +1,55 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use futures_util::future::BoxFuture;
+
+use crate::errors::{ChorusLibError, ChorusResult};
+use crate::instance::Instance;
+use crate::types::CaptchaChallenge;
+
+/// How often a request is retried with a new captcha solution, before giving up.
+pub const CAPTCHA_ATTEMPTS: u8 = 3;
+
+/// Solves the captcha challenges an instance with captchas enabled sends in response to
+/// registering or logging in, e.g. by showing the captcha to the user.
+///
+/// Set it using [`Instance::set_captcha_solver`].
+pub trait CaptchaSolver: Sync + Send + Debug {
+    /// Solves the given challenge.
+    ///
+    /// # Returns
+    /// The `captcha_key` to send the request again with, or [`None`] to give up.
+    fn solve<'a>(&'a self, challenge: &'a CaptchaChallenge) -> BoxFuture<'a, Option<String>>;
+}
+
+impl Instance {
+    /// Sets the [`CaptchaSolver`] which [`Instance::register_account`] and
+    /// [`Instance::login_account`] ask to solve captcha challenges. Without one, these methods
+    /// return a [`ChorusLibError::CaptchaRequired`] error instead.
+    pub fn set_captcha_solver(&mut self, solver: Option<Arc<dyn CaptchaSolver>>) {
+        self.captcha_solver = solver;
+    }
+
+    /// Asks the captcha solver of this instance for a `captcha_key`, if `error` is a captcha
+    /// challenge and there are attempts left. Otherwise, `error` is returned.
+    pub(crate) async fn solve_captcha(
+        &self,
+        error: ChorusLibError,
+        attempts: &mut u8,
+    ) -> ChorusResult<String> {
+        let ChorusLibError::CaptchaRequired { challenge } = &error else {
+            return Err(error);
+        };
+        let Some(solver) = self.captcha_solver.clone() else {
+            return Err(error);
+        };
+        if *attempts >= CAPTCHA_ATTEMPTS {
+            return Err(error);
+        }
+        *attempts += 1;
+        match solver.solve(challenge).await {
+            Some(captcha_key) => Ok(captcha_key),
+            None => Err(error),
+        }
+    }
+}
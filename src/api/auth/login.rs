@@ -2,31 +2,55 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use reqwest::Client;
-use serde_json::{from_str, json};
+use serde_json::{from_str, json, to_string};
 
 use crate::api::limits::LimitType;
 use crate::errors::{ChorusLibError, ChorusResult};
 use crate::instance::{Instance, UserMeta};
 use crate::limit::LimitedRequester;
-use crate::types::{ErrorResponse, LoginResult, LoginSchema};
+use crate::types::{ErrorResponse, LoginResponse, LoginResult, LoginSchema, TotpSchema};
 
 impl Instance {
+    /// Logs in to an account on the Spacebar server.
+    ///
+    /// If the instance responds with a captcha challenge, the challenge is passed to the
+    /// [`crate::api::auth::CaptchaSolver`] of this instance, and the login is retried with its
+    /// solution.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChorusLibError::MfaRequired`] - If the account has two-factor authentication enabled.
+    ///   Finish logging in using [`Instance::login_totp`].
+    /// * [`ChorusLibError::CaptchaRequired`] - If a captcha has to be solved and the captcha
+    ///   solver is unset or gave up.
+    /// * [`ChorusLibError`] - If the login fails.
     pub async fn login_account(&mut self, login_schema: &LoginSchema) -> ChorusResult<UserMeta> {
-        let json_schema = json!(login_schema);
-        let client = Client::new();
-        let endpoint_url = self.urls.api.clone() + "/auth/login";
-        let request_builder = client.post(endpoint_url).body(json_schema.to_string());
-        // We do not have a user yet, and the UserRateLimits will not be affected by a login
-        // request (since login is an instance wide limit), which is why we are just cloning the
-        // instances' limits to pass them on as user_rate_limits later.
-        let mut cloned_limits = self.limits.clone();
-        let response = LimitedRequester::send_request(
-            request_builder,
-            LimitType::AuthRegister,
-            self,
-            &mut cloned_limits,
-        )
-        .await?;
+        let mut login_schema = login_schema.clone();
+        let mut captcha_attempts = 0;
+        let response = loop {
+            let json_schema = json!(login_schema);
+            let client = Client::new();
+            let endpoint_url = self.urls.api.clone() + "/auth/login";
+            let request_builder = client.post(endpoint_url).body(json_schema.to_string());
+            // We do not have a user yet, and the UserRateLimits will not be affected by a login
+            // request (since login is an instance wide limit), which is why we are just cloning
+            // the instances' limits to pass them on as user_rate_limits later.
+            let mut cloned_limits = self.limits.clone();
+            match LimitedRequester::send_request(
+                request_builder,
+                LimitType::AuthRegister,
+                self,
+                &mut cloned_limits,
+            )
+            .await
+            {
+                Ok(response) => break response,
+                Err(e) => {
+                    login_schema.captcha_key =
+                        Some(self.solve_captcha(e, &mut captcha_attempts).await?);
+                }
+            }
+        };
 
         let status = response.status();
         let response_text = response.text().await.unwrap();
@@ -42,8 +66,80 @@ impl Instance {
             return Err(ChorusLibError::InvalidFormBodyError { error_type, error });
         }
 
+        let login_response: LoginResponse =
+            from_str(&response_text).map_err(|e| ChorusLibError::InvalidResponseError {
+                error: e.to_string(),
+            })?;
+        match login_response {
+            LoginResponse::Success(login_result) => {
+                self.user_from_login_result(*login_result).await
+            }
+            LoginResponse::MfaRequired(mfa) => {
+                Err(ChorusLibError::MfaRequired { ticket: mfa.ticket })
+            }
+        }
+    }
+
+    /// Finishes logging in to an account with two-factor authentication enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticket` - The ticket of the [`ChorusLibError::MfaRequired`] error returned by
+    ///   [`Instance::login_account`].
+    /// * `code` - The current code of the users' TOTP authenticator.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChorusLibError`] - If the ticket or code is invalid, or the request fails.
+    pub async fn login_totp(&mut self, ticket: &str, code: &str) -> ChorusResult<UserMeta> {
+        let totp_schema = TotpSchema {
+            code: code.to_string(),
+            ticket: ticket.to_string(),
+            ..Default::default()
+        };
+        let request_builder = Client::new()
+            .post(self.urls.api.clone() + "/auth/mfa/totp")
+            .body(to_string(&totp_schema).unwrap());
+        let mut cloned_limits = self.limits.clone();
+        let response = LimitedRequester::send_request(
+            request_builder,
+            LimitType::AuthLogin,
+            self,
+            &mut cloned_limits,
+        )
+        .await?;
+        let response_text =
+            response
+                .text()
+                .await
+                .map_err(|e| ChorusLibError::InvalidResponseError {
+                    error: e.to_string(),
+                })?;
+        let login_result: LoginResult =
+            from_str(&response_text).map_err(|e| ChorusLibError::InvalidResponseError {
+                error: e.to_string(),
+            })?;
+        self.user_from_login_result(login_result).await
+    }
+
+    /// Finishes logging in to an account with two-factor authentication enabled, using one of
+    /// the users' backup codes instead of a TOTP code. The backup code is consumed.
+    ///
+    /// See [`Instance::login_totp`].
+    pub async fn login_backup_code(
+        &mut self,
+        ticket: &str,
+        backup_code: &str,
+    ) -> ChorusResult<UserMeta> {
+        // Spacebar accepts backup codes in place of TOTP codes on the same route.
+        self.login_totp(ticket, backup_code).await
+    }
+
+    async fn user_from_login_result(
+        &mut self,
+        login_result: LoginResult,
+    ) -> ChorusResult<UserMeta> {
         let cloned_limits = self.limits.clone();
-        let login_result: LoginResult = from_str(&response_text).unwrap();
         let object = self.get_user(login_result.token.clone(), None).await?;
         let user = UserMeta::new(
             Rc::new(RefCell::new(self.clone())),
@@ -1,5 +1,15 @@
+pub use captcha::*;
 pub use login::*;
+pub use recovery::*;
 pub use register::*;
+pub use renewal::*;
+pub use restore::*;
+pub use sessions::*;
 
+pub mod captcha;
 pub mod login;
+pub mod recovery;
 pub mod register;
+pub mod renewal;
+pub mod restore;
+pub mod sessions;
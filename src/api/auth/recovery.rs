@@ -0,0 +1,118 @@
+use reqwest::Client;
+use serde_json::{from_str, to_string};
+
+use crate::api::handle_request_as_result;
+use crate::api::limits::LimitType;
+use crate::errors::{ChorusLibError, ChorusResult};
+use crate::instance::{Instance, Token, UserMeta};
+use crate::limit::LimitedRequester;
+use crate::types::{ForgotPasswordSchema, PasswordResetSchema, VerifyEmailSchema};
+
+impl Instance {
+    /// Requests an email with a link to reset the password of an account.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The email address or phone number of the account, and the solution to a
+    ///   captcha, if the instance requires one.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChorusLibError::CaptchaRequired`] - If a captcha has to be solved first.
+    /// * [`ChorusLibError`] - If the request fails.
+    ///
+    /// # Reference
+    /// See <https://docs.spacebar.chat/routes/#post-/auth/forgot/>
+    pub async fn forgot_password(&mut self, schema: &ForgotPasswordSchema) -> ChorusResult<()> {
+        let request = Client::new()
+            .post(format!("{}/auth/forgot/", self.urls.api))
+            .body(to_string(schema).unwrap());
+        self.send_unauthenticated(request, LimitType::AuthLogin)
+            .await
+            .map(|_| ())
+    }
+
+    /// Sets a new password using the token of a password reset email, and logs in.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token from the link in the password reset email.
+    /// * `password` - The new password.
+    ///
+    /// # Reference
+    /// See <https://docs.spacebar.chat/routes/#post-/auth/reset/>
+    pub async fn reset_password(&mut self, token: &str, password: &str) -> ChorusResult<UserMeta> {
+        let schema = PasswordResetSchema {
+            token: token.to_string(),
+            password: password.to_string(),
+        };
+        let request = Client::new()
+            .post(format!("{}/auth/reset/", self.urls.api))
+            .body(to_string(&schema).unwrap());
+        let response_text = self
+            .send_unauthenticated(request, LimitType::AuthLogin)
+            .await?;
+        self.user_from_token(parse_token(&response_text)?).await
+    }
+
+    /// Verifies the email address of an account using the token of a verification email, and
+    /// logs in.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChorusLibError::CaptchaRequired`] - If a captcha has to be solved first.
+    /// * [`ChorusLibError`] - If the token is invalid or the request fails.
+    ///
+    /// # Reference
+    /// See <https://docs.spacebar.chat/routes/#post-/auth/verify/>
+    pub async fn verify_email(&mut self, schema: &VerifyEmailSchema) -> ChorusResult<UserMeta> {
+        let request = Client::new()
+            .post(format!("{}/auth/verify/", self.urls.api))
+            .body(to_string(schema).unwrap());
+        let response_text = self
+            .send_unauthenticated(request, LimitType::AuthLogin)
+            .await?;
+        self.user_from_token(parse_token(&response_text)?).await
+    }
+
+    /// Sends a request on behalf of no user, and returns the body of the response.
+    async fn send_unauthenticated(
+        &mut self,
+        request: reqwest::RequestBuilder,
+        limit_type: LimitType,
+    ) -> ChorusResult<String> {
+        let mut cloned_limits = self.limits.clone();
+        let response =
+            LimitedRequester::send_request(request, limit_type, self, &mut cloned_limits).await?;
+        response
+            .text()
+            .await
+            .map_err(|e| ChorusLibError::InvalidResponseError {
+                error: e.to_string(),
+            })
+    }
+}
+
+fn parse_token(response_text: &str) -> ChorusResult<String> {
+    from_str::<Token>(response_text)
+        .map(|token| token.token)
+        .map_err(|e| ChorusLibError::InvalidResponseError {
+            error: e.to_string(),
+        })
+}
+
+impl UserMeta {
+    /// Sends the verification email to the email address of the current user again.
+    ///
+    /// # Reference
+    /// See <https://docs.spacebar.chat/routes/#post-/auth/verify/resend/>
+    pub async fn resend_verification_email(&mut self) -> ChorusResult<()> {
+        let request = Client::new()
+            .post(format!(
+                "{}/auth/verify/resend/",
+                self.belongs_to.borrow().urls.api
+            ))
+            .bearer_auth(self.token());
+        handle_request_as_result(request, self, LimitType::Global).await
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use futures_util::future::BoxFuture;
+
+use crate::errors::{ChorusLibError, ChorusResult};
+use crate::instance::UserMeta;
+use crate::types::LoginSchema;
+
+/// Provides the credentials a [`UserMeta`] logs in again with, once its token has expired.
+///
+/// Set it using [`UserMeta::set_credential_provider`].
+pub trait CredentialProvider: Sync + Send + Debug {
+    /// Returns the credentials to log in with, or [`None`] to give up.
+    fn credentials(&self) -> BoxFuture<'_, Option<LoginSchema>>;
+
+    /// Returns the current TOTP code, if the account has two-factor authentication enabled, or
+    /// [`None`] to give up.
+    fn totp_code(&self) -> BoxFuture<'_, Option<String>> {
+        Box::pin(async { None })
+    }
+}
+
+impl UserMeta {
+    /// Sets the [`CredentialProvider`], which this user logs in again with once its token has
+    /// expired. Requests which failed because of the expired token are sent again afterwards,
+    /// and the gateway is reconnected.
+    pub fn set_credential_provider(&mut self, provider: Option<Arc<dyn CredentialProvider>>) {
+        self.credential_provider = provider;
+    }
+
+    /// Logs in again using the [`CredentialProvider`] of this user and replaces the token of this
+    /// user with the new one. If the user is connected to the gateway, the gateway is reconnected
+    /// using the new token.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChorusLibError::TokenExpired`] - If no credential provider is set, or it gave up.
+    /// * [`ChorusLibError::InvalidArgumentsError`] - If the credentials belong to another user.
+    /// * [`ChorusLibError::GatewayConnectionError`] - If the gateway could not be reconnected.
+    /// * [`ChorusLibError`] - If logging in fails.
+    pub async fn renew_token(&mut self) -> ChorusResult<()> {
+        let Some(provider) = self.credential_provider.clone() else {
+            return Err(ChorusLibError::TokenExpired);
+        };
+        let Some(login_schema) = provider.credentials().await else {
+            return Err(ChorusLibError::TokenExpired);
+        };
+        // Work on a copy, so the instance isn't borrowed across the requests.
+        let mut instance = self.belongs_to.borrow().clone();
+        let user = match instance.login_account(&login_schema).await {
+            Err(ChorusLibError::MfaRequired { ticket }) => match provider.totp_code().await {
+                Some(code) => instance.login_totp(&ticket, &code).await,
+                None => Err(ChorusLibError::MfaRequired { ticket }),
+            },
+            result => result,
+        };
+        self.belongs_to.borrow_mut().limits = instance.limits;
+        let user = user?;
+        if user.object.id != self.object.id {
+            return Err(ChorusLibError::InvalidArgumentsError {
+                error: format!(
+                    "The credentials belong to the user {}, not {}",
+                    user.object.id, self.object.id
+                ),
+            });
+        }
+        self.set_token(user.token);
+        if self.gateway.is_some() {
+            self.connect_gateway()
+                .await
+                .map_err(|e| ChorusLibError::GatewayConnectionError {
+                    error: e.to_string(),
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Renews the token of this user, but only if the server actually rejects it. The server also
+    /// answers with 403 if the token is valid, but lacks permissions.
+    ///
+    /// # Returns
+    ///
+    /// Whether the token has been renewed.
+    pub(crate) async fn renew_token_if_rejected(&mut self) -> ChorusResult<bool> {
+        let mut instance = self.belongs_to.borrow().clone();
+        let result = instance.get_user(self.token(), None).await;
+        self.belongs_to.borrow_mut().limits = instance.limits;
+        match result {
+            Err(ChorusLibError::TokenExpired) => {
+                self.renew_token().await?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::errors::{ChorusLibError, ChorusResult};
+use crate::instance::{Instance, SavedSession, UserMeta};
+
+impl Instance {
+    /// Restores a user from a [`SavedSession`], without logging in again.
+    ///
+    /// The token of the session is checked by requesting the current user from the server. The
+    /// cached settings of the session are reused.
+    ///
+    /// The gateway session is not resumed: call [`UserMeta::resume_gateway`] with the same
+    /// session afterwards. [`crate::manager::ClientManager::restore`] does both, and is the
+    /// complete way to restore an account.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The [`SavedSession`] to restore, as returned by [`UserMeta::save_session`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ChorusLibError::InvalidArgumentsError`] - If the session belongs to another instance, or
+    ///   its token belongs to another user.
+    /// * [`ChorusLibError::TokenExpired`] - If the token of the session is no longer valid.
+    pub async fn restore_account(&mut self, session: &SavedSession) -> ChorusResult<UserMeta> {
+        if session.urls.api != self.urls.api {
+            return Err(ChorusLibError::InvalidArgumentsError {
+                error: format!(
+                    "The session belongs to the instance at {}, not {}",
+                    session.urls.api, self.urls.api
+                ),
+            });
+        }
+        let object = self.get_user(session.token.clone(), None).await?;
+        if object.id != session.user_id {
+            return Err(ChorusLibError::InvalidArgumentsError {
+                error: format!(
+                    "The token of the session belongs to the user {}, not {}",
+                    object.id, session.user_id
+                ),
+            });
+        }
+        let user = UserMeta::new(
+            Rc::new(RefCell::new(self.clone())),
+            session.token.clone(),
+            self.limits.clone(),
+            session.settings.clone(),
+            object,
+        );
+        Ok(user)
+    }
+
+    /// Creates a [`UserMeta`] for the user a freshly received token belongs to, by fetching the
+    /// user and its settings.
+    pub(crate) async fn user_from_token(&mut self, token: String) -> ChorusResult<UserMeta> {
+        let object = self.get_user(token.clone(), None).await?;
+        let settings = UserMeta::get_settings(&token, &self.urls.api.clone(), self).await?;
+        Ok(UserMeta::new(
+            Rc::new(RefCell::new(self.clone())),
+            token,
+            self.limits.clone(),
+            settings,
+            object,
+        ))
+    }
+}
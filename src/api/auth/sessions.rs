@@ -0,0 +1,68 @@
+use reqwest::Client;
+use serde_json::to_string;
+
+use crate::{
+    api::{deserialize_response, handle_request_as_result},
+    errors::ChorusResult,
+    instance::UserMeta,
+    types::{AuthSession, AuthSessionsResponse, LogoutSchema, SessionsLogoutSchema},
+};
+
+impl UserMeta {
+    /// Logs out the current user, which invalidates its token. The gateway connection of the user
+    /// is closed.
+    ///
+    /// # Reference
+    /// See <https://discord-userdoccers.vercel.app/authentication#logout>
+    pub async fn logout(mut self) -> ChorusResult<()> {
+        self.disconnect_gateway().await;
+        let request = Client::new()
+            .post(format!(
+                "{}/auth/logout/",
+                self.belongs_to.borrow().urls.api
+            ))
+            .body(to_string(&LogoutSchema::default()).unwrap())
+            .bearer_auth(self.token());
+        handle_request_as_result(request, &mut self, crate::api::limits::LimitType::Global).await
+    }
+
+    /// Gets the active sessions of the current user.
+    ///
+    /// # Reference
+    /// See <https://discord-userdoccers.vercel.app/resources/user#get-user-sessions>
+    pub async fn get_sessions(&mut self) -> ChorusResult<Vec<AuthSession>> {
+        let request = Client::new()
+            .get(format!(
+                "{}/auth/sessions/",
+                self.belongs_to.borrow().urls.api
+            ))
+            .bearer_auth(self.token());
+        let response = deserialize_response::<AuthSessionsResponse>(
+            request,
+            self,
+            crate::api::limits::LimitType::Global,
+        )
+        .await?;
+        Ok(response.user_sessions)
+    }
+
+    /// Revokes sessions of the current user, which invalidates their tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id_hashes` - The `id_hash`es of the [`AuthSession`]s to revoke.
+    ///
+    /// # Reference
+    /// See <https://discord-userdoccers.vercel.app/resources/user#logout-user-sessions>
+    pub async fn revoke_sessions(&mut self, session_id_hashes: Vec<String>) -> ChorusResult<()> {
+        let schema = SessionsLogoutSchema { session_id_hashes };
+        let request = Client::new()
+            .post(format!(
+                "{}/auth/sessions/logout/",
+                self.belongs_to.borrow().urls.api
+            ))
+            .body(to_string(&schema).unwrap())
+            .bearer_auth(self.token());
+        handle_request_as_result(request, self, crate::api::limits::LimitType::Global).await
+    }
+}
@@ -0,0 +1,402 @@
+use reqwest::Client;
+
+use crate::errors::{ChorusLibError, ChorusResult};
+use crate::instance::{Instance, UserMeta};
+use crate::types::{Channel, Emoji, Guild, PublicUser, RoleObject, Snowflake, Sticker, User};
+
+/// The smallest and largest size an image can be requested in from the CDN.
+pub const MIN_IMAGE_SIZE: u16 = 16;
+pub const MAX_IMAGE_SIZE: u16 = 4096;
+
+/// The file format to request an asset in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Webp,
+    /// Only available for animated assets.
+    Gif,
+    /// Only available for Lottie stickers.
+    Json,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Webp => "webp",
+            ImageFormat::Gif => "gif",
+            ImageFormat::Json => "json",
+        }
+    }
+}
+
+/// An image or other file hosted on the CDN of an instance. Most assets are identified by the id
+/// of the entity they belong to and a hash, which starts with `a_` for animated assets.
+///
+/// The assets of entities are most easily created using methods such as [`User::avatar_asset`]
+/// or [`Guild::icon_asset`].
+///
+/// See <https://discord.com/developers/docs/reference#image-formatting>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CdnAsset {
+    UserAvatar {
+        user_id: Snowflake,
+        hash: String,
+    },
+    /// The avatar of a user without a custom avatar.
+    DefaultAvatar {
+        index: u64,
+    },
+    UserBanner {
+        user_id: Snowflake,
+        hash: String,
+    },
+    GuildMemberAvatar {
+        guild_id: Snowflake,
+        user_id: Snowflake,
+        hash: String,
+    },
+    GuildIcon {
+        guild_id: Snowflake,
+        hash: String,
+    },
+    GuildSplash {
+        guild_id: Snowflake,
+        hash: String,
+    },
+    GuildDiscoverySplash {
+        guild_id: Snowflake,
+        hash: String,
+    },
+    GuildBanner {
+        guild_id: Snowflake,
+        hash: String,
+    },
+    RoleIcon {
+        role_id: Snowflake,
+        hash: String,
+    },
+    /// The icon of a group DM.
+    ChannelIcon {
+        channel_id: Snowflake,
+        hash: String,
+    },
+    Emoji {
+        emoji_id: Snowflake,
+        animated: bool,
+    },
+    Sticker {
+        sticker_id: Snowflake,
+        format_type: u8,
+    },
+}
+
+impl CdnAsset {
+    /// The default avatar of a user. Users with a legacy discriminator have one of 5 default
+    /// avatars based on it, other users one of 6 based on their id.
+    pub fn default_avatar(user_id: Snowflake, discriminator: Option<&str>) -> Self {
+        let index = match discriminator.and_then(|discriminator| discriminator.parse::<u64>().ok())
+        {
+            Some(discriminator) if discriminator != 0 => discriminator % 5,
+            _ => (u64::from(user_id) >> 22) % 6,
+        };
+        CdnAsset::DefaultAvatar { index }
+    }
+
+    /// The path of the asset on the CDN, without the file extension.
+    pub fn path(&self) -> String {
+        match self {
+            CdnAsset::UserAvatar { user_id, hash } => format!("avatars/{}/{}", user_id, hash),
+            CdnAsset::DefaultAvatar { index } => format!("embed/avatars/{}", index),
+            CdnAsset::UserBanner { user_id, hash } => format!("banners/{}/{}", user_id, hash),
+            CdnAsset::GuildMemberAvatar {
+                guild_id,
+                user_id,
+                hash,
+            } => format!("guilds/{}/users/{}/avatars/{}", guild_id, user_id, hash),
+            CdnAsset::GuildIcon { guild_id, hash } => format!("icons/{}/{}", guild_id, hash),
+            CdnAsset::GuildSplash { guild_id, hash } => format!("splashes/{}/{}", guild_id, hash),
+            CdnAsset::GuildDiscoverySplash { guild_id, hash } => {
+                format!("discovery-splashes/{}/{}", guild_id, hash)
+            }
+            CdnAsset::GuildBanner { guild_id, hash } => format!("banners/{}/{}", guild_id, hash),
+            CdnAsset::RoleIcon { role_id, hash } => format!("role-icons/{}/{}", role_id, hash),
+            CdnAsset::ChannelIcon { channel_id, hash } => {
+                format!("channel-icons/{}/{}", channel_id, hash)
+            }
+            CdnAsset::Emoji { emoji_id, .. } => format!("emojis/{}", emoji_id),
+            CdnAsset::Sticker { sticker_id, .. } => format!("stickers/{}", sticker_id),
+        }
+    }
+
+    pub fn is_animated(&self) -> bool {
+        match self {
+            CdnAsset::UserAvatar { hash, .. }
+            | CdnAsset::UserBanner { hash, .. }
+            | CdnAsset::GuildMemberAvatar { hash, .. }
+            | CdnAsset::GuildIcon { hash, .. }
+            | CdnAsset::GuildBanner { hash, .. } => hash.starts_with("a_"),
+            CdnAsset::Emoji { animated, .. } => *animated,
+            CdnAsset::Sticker { format_type, .. } => *format_type == 4,
+            _ => false,
+        }
+    }
+
+    /// The format the asset is requested in if none is given: GIF for animated assets, JSON for
+    /// Lottie stickers and PNG otherwise.
+    pub fn default_format(&self) -> ImageFormat {
+        match self {
+            CdnAsset::Sticker { format_type: 3, .. } => ImageFormat::Json,
+            _ if self.is_animated() => ImageFormat::Gif,
+            _ => ImageFormat::Png,
+        }
+    }
+
+    /// The URL of the asset on the CDN at `cdn_url`, see [`crate::UrlBundle::cdn`].
+    /// # Arguments
+    /// * `format` - The format to request, [`CdnAsset::default_format`] if [`None`].
+    /// * `size` - The size of the image, rounded up to the next power of two between
+    ///   [`MIN_IMAGE_SIZE`] and [`MAX_IMAGE_SIZE`]. Ignored by the CDN for some assets.
+    pub fn url(&self, cdn_url: &str, format: Option<ImageFormat>, size: Option<u16>) -> String {
+        let format = format.unwrap_or_else(|| self.default_format());
+        let mut url = format!(
+            "{}/{}.{}",
+            cdn_url.trim_end_matches('/'),
+            self.path(),
+            format.extension()
+        );
+        if let Some(size) = size {
+            let size = size
+                .clamp(MIN_IMAGE_SIZE, MAX_IMAGE_SIZE)
+                .next_power_of_two();
+            url.push_str(&format!("?size={}", size));
+        }
+        url
+    }
+}
+
+/// Downloads an asset from `url`.
+async fn download(client: &Client, url: String) -> ChorusResult<Vec<u8>> {
+    let response =
+        client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|error| ChorusLibError::RequestErrorError {
+                url: url.clone(),
+                error: error.to_string(),
+            })?;
+    if !response.status().is_success() {
+        return Err(ChorusLibError::ReceivedErrorCodeError {
+            error_code: response.status().to_string(),
+        });
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|error| ChorusLibError::RequestErrorError {
+            url,
+            error: error.to_string(),
+        })?;
+    Ok(bytes.to_vec())
+}
+
+impl Instance {
+    /// The URL of an asset on the CDN of this instance, see [`CdnAsset::url`].
+    pub fn cdn_url(
+        &self,
+        asset: &CdnAsset,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> String {
+        asset.url(&self.urls.cdn, format, size)
+    }
+
+    /// Downloads an asset from the CDN of this instance, using the client of the instance.
+    /// # Errors
+    /// * [`ChorusLibError::RequestErrorError`] - If the asset cannot be downloaded.
+    /// * [`ChorusLibError::ReceivedErrorCodeError`] - If the CDN responds with an error, for
+    ///   example because the asset does not exist.
+    pub async fn download_asset(
+        &self,
+        asset: &CdnAsset,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> ChorusResult<Vec<u8>> {
+        download(&self.client, self.cdn_url(asset, format, size)).await
+    }
+}
+
+impl UserMeta {
+    /// Downloads an asset from the CDN of the instance of the user, see
+    /// [`Instance::download_asset`].
+    pub async fn download_asset(
+        &self,
+        asset: &CdnAsset,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> ChorusResult<Vec<u8>> {
+        let (client, url) = {
+            let instance = self.belongs_to.borrow();
+            (
+                instance.client.clone(),
+                instance.cdn_url(asset, format, size),
+            )
+        };
+        download(&client, url).await
+    }
+}
+
+impl User {
+    /// The avatar of the user, or their default avatar if they have not set one.
+    pub fn avatar_asset(&self) -> CdnAsset {
+        match &self.avatar {
+            Some(hash) => CdnAsset::UserAvatar {
+                user_id: self.id,
+                hash: hash.clone(),
+            },
+            None => CdnAsset::default_avatar(self.id, Some(&self.discriminator)),
+        }
+    }
+
+    pub fn banner_asset(&self) -> Option<CdnAsset> {
+        Some(CdnAsset::UserBanner {
+            user_id: self.id,
+            hash: self.banner.clone()?,
+        })
+    }
+}
+
+impl PublicUser {
+    /// The avatar of the user, or their default avatar if they have not set one.
+    pub fn avatar_asset(&self) -> CdnAsset {
+        match &self.avatar {
+            Some(hash) => CdnAsset::UserAvatar {
+                user_id: self.id,
+                hash: hash.clone(),
+            },
+            None => CdnAsset::default_avatar(self.id, self.discriminator.as_deref()),
+        }
+    }
+
+    pub fn banner_asset(&self) -> Option<CdnAsset> {
+        Some(CdnAsset::UserBanner {
+            user_id: self.id,
+            hash: self.banner.clone()?,
+        })
+    }
+}
+
+impl Guild {
+    pub fn icon_asset(&self) -> Option<CdnAsset> {
+        Some(CdnAsset::GuildIcon {
+            guild_id: self.id,
+            hash: self.icon.clone()?,
+        })
+    }
+
+    pub fn splash_asset(&self) -> Option<CdnAsset> {
+        Some(CdnAsset::GuildSplash {
+            guild_id: self.id,
+            hash: self.splash.clone()?,
+        })
+    }
+
+    pub fn discovery_splash_asset(&self) -> Option<CdnAsset> {
+        Some(CdnAsset::GuildDiscoverySplash {
+            guild_id: self.id,
+            hash: self.discovery_splash.clone()?,
+        })
+    }
+
+    pub fn banner_asset(&self) -> Option<CdnAsset> {
+        Some(CdnAsset::GuildBanner {
+            guild_id: self.id,
+            hash: self.banner.clone()?,
+        })
+    }
+}
+
+impl RoleObject {
+    pub fn icon_asset(&self) -> Option<CdnAsset> {
+        Some(CdnAsset::RoleIcon {
+            role_id: self.id,
+            hash: self.icon.clone()?,
+        })
+    }
+}
+
+impl Channel {
+    /// The icon of a group DM.
+    pub fn icon_asset(&self) -> Option<CdnAsset> {
+        Some(CdnAsset::ChannelIcon {
+            channel_id: self.id,
+            hash: self.icon.clone()?,
+        })
+    }
+}
+
+impl Emoji {
+    /// The image of a custom emoji. Unicode emoji have no id and are not hosted on the CDN.
+    pub fn asset(&self) -> Option<CdnAsset> {
+        Some(CdnAsset::Emoji {
+            emoji_id: self.id?,
+            animated: self.animated.unwrap_or(false),
+        })
+    }
+}
+
+impl Sticker {
+    pub fn asset(&self) -> CdnAsset {
+        CdnAsset::Sticker {
+            sticker_id: self.id,
+            format_type: self.format_type,
+        }
+    }
+}
+
+#[cfg(test)]
+mod cdn {
+    use super::*;
+
+    #[test]
+    fn asset_urls() {
+        let id = Snowflake::from(175928847299117063);
+        let avatar = CdnAsset::UserAvatar {
+            user_id: id,
+            hash: "a_1234".to_string(),
+        };
+        assert_eq!(
+            avatar.url("https://cdn.example.org/", None, Some(100)),
+            "https://cdn.example.org/avatars/175928847299117063/a_1234.gif?size=128"
+        );
+        assert_eq!(
+            avatar.url(
+                "https://cdn.example.org",
+                Some(ImageFormat::Webp),
+                Some(8000)
+            ),
+            "https://cdn.example.org/avatars/175928847299117063/a_1234.webp?size=4096"
+        );
+        assert_eq!(
+            CdnAsset::default_avatar(id, Some("0042")).path(),
+            "embed/avatars/2"
+        );
+        assert_eq!(
+            CdnAsset::default_avatar(id, Some("0")),
+            CdnAsset::DefaultAvatar {
+                index: (175928847299117063 >> 22) % 6
+            }
+        );
+        let sticker = CdnAsset::Sticker {
+            sticker_id: id,
+            format_type: 3,
+        };
+        assert_eq!(
+            sticker.url("https://cdn.example.org", None, None),
+            "https://cdn.example.org/stickers/175928847299117063.json"
+        );
+    }
+}
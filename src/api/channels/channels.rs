@@ -75,6 +75,7 @@ impl Channel {
         channel_id: Snowflake,
         user: &mut UserMeta,
     ) -> ChorusResult<()> {
+        user.belongs_to.borrow().validate(&modify_data)?;
         let request = Client::new()
             .patch(format!(
                 "{}/channels/{}/",
@@ -0,0 +1,127 @@
+use reqwest::Client;
+use serde_json::to_string;
+
+use crate::api::deserialize_response;
+use crate::errors::ChorusResult;
+use crate::instance::UserMeta;
+use crate::types::{
+    Channel, ForumPostCreateSchema, ForumPostResponse, Snowflake, TagCreateSchema,
+    ThreadModifySchema,
+};
+
+impl Channel {
+    /// Creates a post in a forum channel, that is a thread with a starter message.
+    /// # Arguments
+    /// * `forum_id` - The id of the forum channel.
+    /// * `schema` - The name, tags and starter message of the post.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#start-thread-in-forum-or-media-channel](https://discord.com/developers/docs/resources/channel#start-thread-in-forum-or-media-channel)
+    pub async fn create_forum_post(
+        user: &mut UserMeta,
+        forum_id: Snowflake,
+        schema: &ForumPostCreateSchema,
+    ) -> ChorusResult<ForumPostResponse> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/channels/{}/threads",
+            user.belongs_to.borrow().urls.api,
+            forum_id
+        );
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<ForumPostResponse>(
+            request,
+            user,
+            crate::api::limits::LimitType::Channel,
+        )
+        .await
+    }
+
+    /// Replaces the tags applied to a forum post.
+    /// # Arguments
+    /// * `post_id` - The id of the thread of the post.
+    /// * `tags` - The ids of the tags of the forum to apply, at most
+    ///   [`crate::types::MAX_APPLIED_TAGS`].
+    pub async fn set_forum_post_tags(
+        user: &mut UserMeta,
+        post_id: Snowflake,
+        tags: Vec<Snowflake>,
+    ) -> ChorusResult<Channel> {
+        let schema = ThreadModifySchema {
+            applied_tags: Some(tags),
+            ..Default::default()
+        };
+        Channel::modify_thread(user, post_id, &schema).await
+    }
+
+    /// Adds a tag to a forum channel. Requires the `MANAGE_CHANNELS` permission.
+    /// # Returns
+    /// The forum channel, including the new tag in its `available_tags`.
+    /// # Reference
+    /// See [https://discord-userdoccers.vercel.app/resources/channel#create-forum-tag](https://discord-userdoccers.vercel.app/resources/channel#create-forum-tag)
+    pub async fn create_tag(
+        user: &mut UserMeta,
+        forum_id: Snowflake,
+        schema: &TagCreateSchema,
+    ) -> ChorusResult<Channel> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/channels/{}/tags",
+            user.belongs_to.borrow().urls.api,
+            forum_id
+        );
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Channel>(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Edits a tag of a forum channel. Requires the `MANAGE_CHANNELS` permission.
+    /// # Returns
+    /// The forum channel, including the edited tag in its `available_tags`.
+    /// # Reference
+    /// See [https://discord-userdoccers.vercel.app/resources/channel#modify-forum-tag](https://discord-userdoccers.vercel.app/resources/channel#modify-forum-tag)
+    pub async fn modify_tag(
+        user: &mut UserMeta,
+        forum_id: Snowflake,
+        tag_id: Snowflake,
+        schema: &TagCreateSchema,
+    ) -> ChorusResult<Channel> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/channels/{}/tags/{}",
+            user.belongs_to.borrow().urls.api,
+            forum_id,
+            tag_id
+        );
+        let request = Client::new()
+            .put(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Channel>(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Deletes a tag of a forum channel, removing it from all posts. Requires the
+    /// `MANAGE_CHANNELS` permission.
+    /// # Returns
+    /// The forum channel, without the deleted tag.
+    /// # Reference
+    /// See [https://discord-userdoccers.vercel.app/resources/channel#delete-forum-tag](https://discord-userdoccers.vercel.app/resources/channel#delete-forum-tag)
+    pub async fn delete_tag(
+        user: &mut UserMeta,
+        forum_id: Snowflake,
+        tag_id: Snowflake,
+    ) -> ChorusResult<Channel> {
+        let url = format!(
+            "{}/channels/{}/tags/{}",
+            user.belongs_to.borrow().urls.api,
+            forum_id,
+            tag_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        deserialize_response::<Channel>(request, user, crate::api::limits::LimitType::Channel).await
+    }
+}
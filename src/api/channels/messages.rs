@@ -1,11 +1,14 @@
-use http::header::CONTENT_DISPOSITION;
-use http::HeaderMap;
-use reqwest::{multipart, Client};
+use reqwest::Client;
 use serde_json::to_string;
 
-use crate::api::deserialize_response;
+use crate::api::common::{multipart_form, validate_message};
+use crate::api::{deserialize_response, handle_request_as_result};
+use crate::errors::{ChorusLibError, ChorusResult};
 use crate::instance::UserMeta;
-use crate::types::{Message, MessageSendSchema, PartialDiscordFileAttachment, Snowflake};
+use crate::types::{
+    FileUpload, Message, MessageBulkDeleteSchema, MessageModifySchema, MessageSendSchema,
+    Snowflake, Validate,
+};
 
 impl Message {
     /**
@@ -13,19 +16,34 @@ impl Message {
     # Arguments
     * `url_api` - The URL of the Spacebar server's API.
     * `message` - The [`Message`] that will be sent to the Spacebar server.
+    * `files` - The files to attach to the message. The `attachments` of the message are replaced
+      with their metadata.
     * `limits_user` - The [`Limits`] of the user.
     * `limits_instance` - The [`Limits`] of the instance.
     * `requester` - The [`LimitedRequester`] that will be used to make requests to the Spacebar server.
     # Errors
+    * [`ChorusLibError::ValidationError`] - If the message violates the limits of the instance,
+      or a file is larger than the maximum attachment size.
+    * [`ChorusLibError::MultipartCreationError`] - If a file cannot be opened.
     * [`ChorusLibError`] - If the message cannot be sent.
+
+    If the message does not set its allowed mentions, the default allowed mentions of the
+    instance are used, see [`crate::instance::Instance::set_default_allowed_mentions`].
      */
     pub async fn send(
         user: &mut UserMeta,
         channel_id: Snowflake,
         message: &mut MessageSendSchema,
-        files: Option<Vec<PartialDiscordFileAttachment>>,
+        files: Option<Vec<FileUpload>>,
     ) -> Result<Message, crate::errors::ChorusLibError> {
         let url_api = user.belongs_to.borrow().urls.api.clone();
+        validate_message(&user.belongs_to.borrow(), &*message)?;
+        if message.allowed_mentions.is_none() {
+            message.allowed_mentions = user.belongs_to.borrow().default_allowed_mentions.clone();
+        }
+        if let Some(files) = &files {
+            user.belongs_to.borrow().validate(files)?;
+        }
 
         if files.is_none() {
             let request = Client::new()
@@ -35,33 +53,15 @@ impl Message {
             deserialize_response::<Message>(request, user, crate::api::limits::LimitType::Channel)
                 .await
         } else {
-            for (index, attachment) in message.attachments.iter_mut().enumerate() {
-                attachment.get_mut(index).unwrap().set_id(index as i16);
-            }
-            let mut form = reqwest::multipart::Form::new();
-            let payload_json = to_string(message).unwrap();
-            let payload_field = reqwest::multipart::Part::text(payload_json);
-
-            form = form.part("payload_json", payload_field);
-
-            for (index, attachment) in files.unwrap().into_iter().enumerate() {
-                let (attachment_content, current_attachment) = attachment.move_content();
-                let (attachment_filename, _) = current_attachment.move_filename();
-                let part_name = format!("files[{}]", index);
-                let content_disposition = format!(
-                    "form-data; name=\"{}\"'; filename=\"{}\"",
-                    part_name, &attachment_filename
-                );
-                let mut header_map = HeaderMap::new();
-                header_map.insert(CONTENT_DISPOSITION, content_disposition.parse().unwrap());
-
-                let part = multipart::Part::bytes(attachment_content)
-                    .file_name(attachment_filename)
-                    .headers(header_map);
-
-                form = form.part(part_name, part);
-            }
-
+            let files = files.unwrap();
+            message.attachments = Some(
+                files
+                    .iter()
+                    .enumerate()
+                    .map(|(index, file)| file.attachment(index as i16))
+                    .collect(),
+            );
+            let form = multipart_form(&*message, files).await?;
             let request = Client::new()
                 .post(format!("{}/channels/{}/messages/", url_api, channel_id))
                 .bearer_auth(user.token())
@@ -71,6 +71,144 @@ impl Message {
                 .await
         }
     }
+
+    /// Edits a message. Only the content, embeds, allowed mentions, components, flags and the
+    /// attachments to keep can be changed.
+    /// # Arguments
+    /// * `channel_id` - The id of the channel the message was sent in.
+    /// * `message_id` - The id of the message to edit.
+    /// * `schema` - The changes to make to the message.
+    /// # Errors
+    /// * [`ChorusLibError::ValidationError`] - If the new content violates the limits of the instance.
+    /// * [`ChorusLibError`] - If the message cannot be edited.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#edit-message](https://discord.com/developers/docs/resources/channel#edit-message)
+    pub async fn edit(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        schema: &MessageModifySchema,
+    ) -> ChorusResult<Message> {
+        validate_message(&user.belongs_to.borrow(), schema)?;
+        let url = format!(
+            "{}/channels/{}/messages/{}",
+            user.belongs_to.borrow().urls.api,
+            channel_id,
+            message_id
+        );
+        let request = Client::new()
+            .patch(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Message>(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Deletes a message. Deleting messages of other users requires the `MANAGE_MESSAGES`
+    /// permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#delete-message](https://discord.com/developers/docs/resources/channel#delete-message)
+    pub async fn delete(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/messages/{}",
+            user.belongs_to.borrow().urls.api,
+            channel_id,
+            message_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Deletes multiple messages at once. Requires the `MANAGE_MESSAGES` permission.
+    /// # Errors
+    /// * [`ChorusLibError::ValidationError`] - If less than 2 or more than `maxBulkDelete` messages
+    ///   are given, or if one of the messages is older than
+    ///   [`crate::types::MAX_BULK_DELETE_AGE_DAYS`]. The age of the messages is checked even if
+    ///   the limits of the instance are unknown.
+    /// * [`ChorusLibError`] - If the messages cannot be deleted.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#bulk-delete-messages](https://discord.com/developers/docs/resources/channel#bulk-delete-messages)
+    pub async fn bulk_delete(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        schema: &MessageBulkDeleteSchema,
+    ) -> ChorusResult<()> {
+        let limits = user
+            .belongs_to
+            .borrow()
+            .limits_information
+            .clone()
+            .unwrap_or_default();
+        schema
+            .validate(&limits)
+            .map_err(|error| ChorusLibError::ValidationError { error })?;
+        let url = format!(
+            "{}/channels/{}/messages/bulk-delete",
+            user.belongs_to.borrow().urls.api,
+            channel_id
+        );
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Pins a message in its channel. Requires the `MANAGE_MESSAGES` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#pin-message](https://discord.com/developers/docs/resources/channel#pin-message)
+    pub async fn pin(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/pins/{}",
+            user.belongs_to.borrow().urls.api,
+            channel_id,
+            message_id
+        );
+        let request = Client::new().put(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Unpins a message in its channel. Requires the `MANAGE_MESSAGES` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#unpin-message](https://discord.com/developers/docs/resources/channel#unpin-message)
+    pub async fn unpin(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/pins/{}",
+            user.belongs_to.borrow().urls.api,
+            channel_id,
+            message_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Gets all pinned messages of a channel.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#get-pinned-messages](https://discord.com/developers/docs/resources/channel#get-pinned-messages)
+    pub async fn get_pinned(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+    ) -> ChorusResult<Vec<Message>> {
+        let url = format!(
+            "{}/channels/{}/pins",
+            user.belongs_to.borrow().urls.api,
+            channel_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Vec<Message>>(request, user, crate::api::limits::LimitType::Channel)
+            .await
+    }
 }
 
 impl UserMeta {
@@ -90,8 +228,62 @@ impl UserMeta {
         &mut self,
         message: &mut MessageSendSchema,
         channel_id: Snowflake,
-        files: Option<Vec<PartialDiscordFileAttachment>>,
+        files: Option<Vec<FileUpload>>,
     ) -> Result<Message, crate::errors::ChorusLibError> {
         Message::send(self, channel_id, message, files).await
     }
+
+    /// Shorthand call for [`Message::edit`].
+    pub async fn edit_message(
+        &mut self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        schema: &MessageModifySchema,
+    ) -> ChorusResult<Message> {
+        Message::edit(self, channel_id, message_id, schema).await
+    }
+
+    /// Shorthand call for [`Message::delete`].
+    pub async fn delete_message(
+        &mut self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+    ) -> ChorusResult<()> {
+        Message::delete(self, channel_id, message_id).await
+    }
+
+    /// Shorthand call for [`Message::bulk_delete`].
+    pub async fn bulk_delete_messages(
+        &mut self,
+        channel_id: Snowflake,
+        messages: Vec<Snowflake>,
+    ) -> ChorusResult<()> {
+        Message::bulk_delete(self, channel_id, &MessageBulkDeleteSchema { messages }).await
+    }
+
+    /// Shorthand call for [`Message::pin`].
+    pub async fn pin_message(
+        &mut self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+    ) -> ChorusResult<()> {
+        Message::pin(self, channel_id, message_id).await
+    }
+
+    /// Shorthand call for [`Message::unpin`].
+    pub async fn unpin_message(
+        &mut self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+    ) -> ChorusResult<()> {
+        Message::unpin(self, channel_id, message_id).await
+    }
+
+    /// Shorthand call for [`Message::get_pinned`].
+    pub async fn get_pinned_messages(
+        &mut self,
+        channel_id: Snowflake,
+    ) -> ChorusResult<Vec<Message>> {
+        Message::get_pinned(self, channel_id).await
+    }
 }
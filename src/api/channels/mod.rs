@@ -1,9 +1,17 @@
 pub use channels::*;
+pub use forums::*;
 pub use messages::*;
 pub use permissions::*;
+pub use private::*;
 pub use reactions::*;
+pub use search::*;
+pub use threads::*;
 
 pub mod channels;
+pub mod forums;
 pub mod messages;
 pub mod permissions;
+pub mod private;
 pub mod reactions;
+pub mod search;
+pub mod threads;
@@ -0,0 +1,142 @@
+use reqwest::Client;
+use serde_json::to_string;
+
+use crate::api::{deserialize_response, handle_request_as_result};
+use crate::errors::{ChorusLibError, ChorusResult};
+use crate::instance::UserMeta;
+use crate::types::{
+    Channel, GroupDmModifySchema, PrivateChannelCreateSchema, Snowflake, ValidationError,
+};
+
+impl Channel {
+    /// Fetches the DMs and group DMs of the current user.
+    /// # Reference
+    /// See [https://discord-userdoccers.vercel.app/resources/channel#get-private-channels](https://discord-userdoccers.vercel.app/resources/channel#get-private-channels)
+    pub async fn private_channels(user: &mut UserMeta) -> ChorusResult<Vec<Channel>> {
+        let url = format!("{}/users/@me/channels", user.belongs_to.borrow().urls.api);
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Vec<Channel>>(request, user, crate::api::limits::LimitType::Global)
+            .await
+    }
+
+    /// Opens a DM with a user, or returns the existing one. Messages can be sent to it with
+    /// [`crate::types::Message::send`].
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/user#create-dm](https://discord.com/developers/docs/resources/user#create-dm)
+    pub async fn create_dm(user: &mut UserMeta, recipient_id: Snowflake) -> ChorusResult<Channel> {
+        let schema = PrivateChannelCreateSchema {
+            recipients: vec![recipient_id],
+        };
+        Channel::create_private_channel(user, &schema).await
+    }
+
+    /// Creates a group DM with the current user as its owner.
+    /// # Arguments
+    /// * `recipients` - The users to add to the group DM, at most
+    ///   [`crate::types::MAX_GROUP_DM_MEMBERS`] - 1.
+    /// # Errors
+    /// * [`ChorusLibError::ValidationError`] - If there is exactly one recipient, as the server
+    ///   would open a DM instead. Use [`Channel::create_dm`] for that.
+    pub async fn create_group_dm(
+        user: &mut UserMeta,
+        recipients: Vec<Snowflake>,
+    ) -> ChorusResult<Channel> {
+        if recipients.len() == 1 {
+            return Err(ChorusLibError::ValidationError {
+                error: ValidationError::Invalid {
+                    field: "recipients",
+                    reason: "a group DM with one recipient is a DM, use Channel::create_dm instead"
+                        .to_string(),
+                },
+            });
+        }
+        let schema = PrivateChannelCreateSchema { recipients };
+        Channel::create_private_channel(user, &schema).await
+    }
+
+    async fn create_private_channel(
+        user: &mut UserMeta,
+        schema: &PrivateChannelCreateSchema,
+    ) -> ChorusResult<Channel> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!("{}/users/@me/channels", user.belongs_to.borrow().urls.api);
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Channel>(request, user, crate::api::limits::LimitType::Global).await
+    }
+
+    /// Renames a group DM or changes its icon.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#modify-channel-json-params-group-dm](https://discord.com/developers/docs/resources/channel#modify-channel-json-params-group-dm)
+    pub async fn modify_group_dm(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        schema: &GroupDmModifySchema,
+    ) -> ChorusResult<Channel> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/channels/{}",
+            user.belongs_to.borrow().urls.api,
+            channel_id
+        );
+        let request = Client::new()
+            .patch(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Channel>(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Adds a user to a group DM.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#group-dm-add-recipient](https://discord.com/developers/docs/resources/channel#group-dm-add-recipient)
+    pub async fn add_recipient(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        recipient_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/recipients/{}",
+            user.belongs_to.borrow().urls.api,
+            channel_id,
+            recipient_id
+        );
+        let request = Client::new().put(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Removes a user from a group DM. Only the owner of the group DM can remove other users.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#group-dm-remove-recipient](https://discord.com/developers/docs/resources/channel#group-dm-remove-recipient)
+    pub async fn remove_recipient(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        recipient_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/recipients/{}",
+            user.belongs_to.borrow().urls.api,
+            channel_id,
+            recipient_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Closes a DM, or leaves a group DM. A closed DM is reopened by [`Channel::create_dm`].
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#deleteclose-channel](https://discord.com/developers/docs/resources/channel#deleteclose-channel)
+    pub async fn close_private_channel(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}",
+            user.belongs_to.borrow().urls.api,
+            channel_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+}
@@ -0,0 +1,68 @@
+use reqwest::Client;
+
+use crate::api::deserialize_response;
+use crate::errors::ChorusResult;
+use crate::instance::UserMeta;
+use crate::types::{Channel, Guild, MessageSearchQuery, MessageSearchResponse, Snowflake};
+
+impl Guild {
+    /// Searches the messages of all channels of a guild the user can read.
+    /// # Arguments
+    /// * `guild_id` - The id of the guild to search.
+    /// * `query` - The filters to apply, see [`MessageSearchQuery`]. Use
+    ///   [`MessageSearchQuery::next_page`] to get further pages of results.
+    /// # Reference
+    /// See [https://discord-userdoccers.vercel.app/resources/message#search-messages](https://discord-userdoccers.vercel.app/resources/message#search-messages)
+    pub async fn search_messages(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        query: &MessageSearchQuery,
+    ) -> ChorusResult<MessageSearchResponse> {
+        let url = format!(
+            "{}/guilds/{}/messages/search",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let request = Client::new()
+            .get(url)
+            .bearer_auth(user.token())
+            .query(&query.to_query());
+        deserialize_response::<MessageSearchResponse>(
+            request,
+            user,
+            crate::api::limits::LimitType::Guild,
+        )
+        .await
+    }
+}
+
+impl Channel {
+    /// Searches the messages of a single channel, for example a DM.
+    /// # Arguments
+    /// * `channel_id` - The id of the channel to search.
+    /// * `query` - The filters to apply, see [`MessageSearchQuery`]. Use
+    ///   [`MessageSearchQuery::next_page`] to get further pages of results.
+    /// # Reference
+    /// See [https://discord-userdoccers.vercel.app/resources/message#search-messages](https://discord-userdoccers.vercel.app/resources/message#search-messages)
+    pub async fn search_messages(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        query: &MessageSearchQuery,
+    ) -> ChorusResult<MessageSearchResponse> {
+        let url = format!(
+            "{}/channels/{}/messages/search",
+            user.belongs_to.borrow().urls.api,
+            channel_id
+        );
+        let request = Client::new()
+            .get(url)
+            .bearer_auth(user.token())
+            .query(&query.to_query());
+        deserialize_response::<MessageSearchResponse>(
+            request,
+            user,
+            crate::api::limits::LimitType::Channel,
+        )
+        .await
+    }
+}
@@ -0,0 +1,231 @@
+use reqwest::Client;
+use serde_json::to_string;
+
+use crate::api::{deserialize_response, handle_request_as_result};
+use crate::errors::ChorusResult;
+use crate::instance::UserMeta;
+use crate::types::{
+    Channel, GetArchivedThreadsSchema, Guild, Snowflake, ThreadCreateSchema,
+    ThreadFromMessageCreateSchema, ThreadMember, ThreadModifySchema, ThreadsResponse,
+};
+
+impl Channel {
+    /// Starts a thread from a message. The id of the thread is the id of the message.
+    /// # Arguments
+    /// * `channel_id` - The id of the channel the message was sent in.
+    /// * `message_id` - The id of the message to start the thread from.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#start-thread-from-message](https://discord.com/developers/docs/resources/channel#start-thread-from-message)
+    pub async fn create_thread_from_message(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        schema: &ThreadFromMessageCreateSchema,
+    ) -> ChorusResult<Channel> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/channels/{}/messages/{}/threads",
+            user.belongs_to.borrow().urls.api,
+            channel_id,
+            message_id
+        );
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Channel>(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Starts a thread which is not attached to a message.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#start-thread-without-message](https://discord.com/developers/docs/resources/channel#start-thread-without-message)
+    pub async fn create_thread(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        schema: &ThreadCreateSchema,
+    ) -> ChorusResult<Channel> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/channels/{}/threads",
+            user.belongs_to.borrow().urls.api,
+            channel_id
+        );
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Channel>(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Edits a thread, for example to archive or lock it, see [`ThreadModifySchema`].
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#modify-channel-json-params-thread](https://discord.com/developers/docs/resources/channel#modify-channel-json-params-thread)
+    pub async fn modify_thread(
+        user: &mut UserMeta,
+        thread_id: Snowflake,
+        schema: &ThreadModifySchema,
+    ) -> ChorusResult<Channel> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/channels/{}",
+            user.belongs_to.borrow().urls.api,
+            thread_id
+        );
+        let request = Client::new()
+            .patch(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Channel>(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Joins a thread. Requires the thread not to be archived.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#join-thread](https://discord.com/developers/docs/resources/channel#join-thread)
+    pub async fn join_thread(user: &mut UserMeta, thread_id: Snowflake) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/thread-members/@me",
+            user.belongs_to.borrow().urls.api,
+            thread_id
+        );
+        let request = Client::new().put(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Leaves a thread.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#leave-thread](https://discord.com/developers/docs/resources/channel#leave-thread)
+    pub async fn leave_thread(user: &mut UserMeta, thread_id: Snowflake) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/thread-members/@me",
+            user.belongs_to.borrow().urls.api,
+            thread_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Adds another user to a thread. Requires the current user to be a member of the thread.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#add-thread-member](https://discord.com/developers/docs/resources/channel#add-thread-member)
+    pub async fn add_thread_member(
+        user: &mut UserMeta,
+        thread_id: Snowflake,
+        user_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/thread-members/{}",
+            user.belongs_to.borrow().urls.api,
+            thread_id,
+            user_id
+        );
+        let request = Client::new().put(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Removes another user from a thread. Requires the `MANAGE_THREADS` permission, or to be
+    /// the creator of a private thread.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#remove-thread-member](https://discord.com/developers/docs/resources/channel#remove-thread-member)
+    pub async fn remove_thread_member(
+        user: &mut UserMeta,
+        thread_id: Snowflake,
+        user_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/thread-members/{}",
+            user.belongs_to.borrow().urls.api,
+            thread_id,
+            user_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Channel).await
+    }
+
+    /// Gets the members of a thread.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#list-thread-members](https://discord.com/developers/docs/resources/channel#list-thread-members)
+    pub async fn thread_members(
+        user: &mut UserMeta,
+        thread_id: Snowflake,
+    ) -> ChorusResult<Vec<ThreadMember>> {
+        let url = format!(
+            "{}/channels/{}/thread-members",
+            user.belongs_to.borrow().urls.api,
+            thread_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Vec<ThreadMember>>(
+            request,
+            user,
+            crate::api::limits::LimitType::Channel,
+        )
+        .await
+    }
+
+    /// Gets the archived public threads of a channel, newest first. Use
+    /// [`GetArchivedThreadsSchema::next_page`] to get further pages.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#list-public-archived-threads](https://discord.com/developers/docs/resources/channel#list-public-archived-threads)
+    pub async fn public_archived_threads(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        query: &GetArchivedThreadsSchema,
+    ) -> ChorusResult<ThreadsResponse> {
+        Channel::archived_threads(user, channel_id, "public", query).await
+    }
+
+    /// Gets the archived private threads of a channel, newest first. Requires the
+    /// `MANAGE_THREADS` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#list-private-archived-threads](https://discord.com/developers/docs/resources/channel#list-private-archived-threads)
+    pub async fn private_archived_threads(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        query: &GetArchivedThreadsSchema,
+    ) -> ChorusResult<ThreadsResponse> {
+        Channel::archived_threads(user, channel_id, "private", query).await
+    }
+
+    async fn archived_threads(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        visibility: &str,
+        query: &GetArchivedThreadsSchema,
+    ) -> ChorusResult<ThreadsResponse> {
+        let url = format!(
+            "{}/channels/{}/threads/archived/{}",
+            user.belongs_to.borrow().urls.api,
+            channel_id,
+            visibility
+        );
+        let request = Client::new()
+            .get(url)
+            .bearer_auth(user.token())
+            .query(query);
+        deserialize_response::<ThreadsResponse>(
+            request,
+            user,
+            crate::api::limits::LimitType::Channel,
+        )
+        .await
+    }
+}
+
+impl Guild {
+    /// Gets all active threads of a guild the user can see.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/guild#list-active-guild-threads](https://discord.com/developers/docs/resources/guild#list-active-guild-threads)
+    pub async fn active_threads(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+    ) -> ChorusResult<ThreadsResponse> {
+        let url = format!(
+            "{}/guilds/{}/threads/active",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<ThreadsResponse>(request, user, crate::api::limits::LimitType::Guild)
+            .await
+    }
+}
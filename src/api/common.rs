@@ -1,21 +1,62 @@
-use reqwest::RequestBuilder;
-use serde::Deserialize;
-use serde_json::from_str;
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use reqwest::{multipart, Client, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_str, to_string};
 
 use crate::{
     errors::{ChorusLibError, ChorusResult},
-    instance::UserMeta,
+    instance::{Instance, UserMeta},
     limit::LimitedRequester,
+    types::{FileUpload, LimitsConfiguration, Validate},
 };
 
 use super::limits::LimitType;
 
 /// Sends a request to wherever it needs to go and performs some basic error handling.
+///
+/// If the token of the user has expired and the user has a
+/// [`CredentialProvider`](crate::api::auth::CredentialProvider), the token is renewed and the
+/// request is sent again.
 pub async fn handle_request(
     request: RequestBuilder,
     user: &mut UserMeta,
     limit_type: LimitType,
 ) -> Result<reqwest::Response, crate::errors::ChorusLibError> {
+    // Keep a copy of the request, to send it again once the token has been renewed. Requests
+    // with streamed bodies cannot be copied, and are not retried.
+    let retry = match user.credential_provider {
+        Some(_) => request.try_clone(),
+        None => None,
+    };
+    let result = send_request(request, user, limit_type).await;
+    let (Err(ChorusLibError::TokenExpired), Some(retry)) = (&result, retry) else {
+        return result;
+    };
+    if !user.renew_token_if_rejected().await? {
+        return result;
+    }
+    let (client, retry) = retry.build_split();
+    let mut retry = retry.map_err(|e| ChorusLibError::RequestErrorError {
+        url: "".to_string(),
+        error: e.to_string(),
+    })?;
+    let authorization =
+        HeaderValue::from_str(&format!("Bearer {}", user.token())).map_err(|e| {
+            ChorusLibError::RequestErrorError {
+                url: retry.url().to_string(),
+                error: e.to_string(),
+            }
+        })?;
+    retry.headers_mut().insert(AUTHORIZATION, authorization);
+    send_request(RequestBuilder::from_parts(client, retry), user, limit_type).await
+}
+
+async fn send_request(
+    request: RequestBuilder,
+    user: &mut UserMeta,
+    limit_type: LimitType,
+) -> ChorusResult<reqwest::Response> {
     LimitedRequester::send_request(
         request,
         limit_type,
@@ -40,12 +81,18 @@ pub async fn handle_request_as_result(
     }
 }
 
+/// Sends a request like [`handle_request`] and deserializes the body of the response.
+///
+/// # Errors
+/// * [`ChorusLibError`] - If the request fails or the server responds with an error, see
+///   [`LimitedRequester::send_request`].
+/// * [`ChorusLibError::InvalidResponseError`] - If the body cannot be deserialized into `T`.
 pub async fn deserialize_response<T: for<'a> Deserialize<'a>>(
     request: RequestBuilder,
     user: &mut UserMeta,
     limit_type: LimitType,
 ) -> ChorusResult<T> {
-    let response = handle_request(request, user, limit_type).await.unwrap();
+    let response = handle_request(request, user, limit_type).await?;
     let response_text = match response.text().await {
         Ok(string) => string,
         Err(e) => {
@@ -70,3 +117,67 @@ pub async fn deserialize_response<T: for<'a> Deserialize<'a>>(
     };
     Ok(object)
 }
+
+/// Sends an unauthenticated GET request to `url` and deserializes the response body.
+pub(crate) async fn get_json<T: DeserializeOwned>(url: String) -> ChorusResult<T> {
+    let response = match Client::new().get(&url).send().await {
+        Ok(response) => response,
+        Err(e) => {
+            return Err(ChorusLibError::RequestErrorError {
+                url,
+                error: e.to_string(),
+            });
+        }
+    };
+    if !response.status().is_success() {
+        return Err(ChorusLibError::ReceivedErrorCodeError {
+            error_code: response.status().to_string(),
+        });
+    }
+    let body = response
+        .text()
+        .await
+        .map_err(|e| ChorusLibError::InvalidResponseError {
+            error: e.to_string(),
+        })?;
+    from_str::<T>(&body).map_err(|e| ChorusLibError::InvalidResponseError {
+        error: format!(
+            "Error while trying to deserialize the response of {}: {}",
+            url, e
+        ),
+    })
+}
+
+/// Builds a multipart form from a JSON payload and the files attached to it, in the order their
+/// metadata was added to the `attachments` of the payload.
+pub(crate) async fn multipart_form<T: Serialize>(
+    payload: &T,
+    files: Vec<FileUpload>,
+) -> ChorusResult<multipart::Form> {
+    let payload_json = to_string(payload).unwrap();
+    let mut form = multipart::Form::new().part("payload_json", multipart::Part::text(payload_json));
+    for (index, file) in files.into_iter().enumerate() {
+        let part =
+            file.into_part()
+                .await
+                .map_err(|error| ChorusLibError::MultipartCreationError {
+                    error: error.to_string(),
+                })?;
+        form = form.part(format!("files[{}]", index), part);
+    }
+    Ok(form)
+}
+
+/// Validates a message against the limits of the instance. The limits of embeds and components
+/// do not depend on the instance, so messages are checked against the default limits if those of
+/// the instance are unknown.
+pub(crate) fn validate_message<T: Validate>(instance: &Instance, message: &T) -> ChorusResult<()> {
+    let default_limits = LimitsConfiguration::default();
+    let limits = instance
+        .limits_information
+        .as_ref()
+        .unwrap_or(&default_limits);
+    message
+        .validate(limits)
+        .map_err(|error| ChorusLibError::ValidationError { error })
+}
@@ -0,0 +1,107 @@
+use reqwest::Client;
+use serde_json::to_string;
+
+use crate::api::{deserialize_response, handle_request_as_result};
+use crate::errors::ChorusResult;
+use crate::instance::UserMeta;
+use crate::types::{Emoji, EmojiCreateSchema, EmojiModifySchema, Snowflake};
+
+impl Emoji {
+    /// Fetches the custom emojis of a guild.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/emoji#list-guild-emojis](https://discord.com/developers/docs/resources/emoji#list-guild-emojis)
+    pub async fn get_all(user: &mut UserMeta, guild_id: Snowflake) -> ChorusResult<Vec<Emoji>> {
+        let url = format!(
+            "{}/guilds/{}/emojis",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Vec<Emoji>>(request, user, crate::api::limits::LimitType::Guild)
+            .await
+    }
+
+    /// Fetches a custom emoji of a guild.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/emoji#get-guild-emoji](https://discord.com/developers/docs/resources/emoji#get-guild-emoji)
+    pub async fn get(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        emoji_id: Snowflake,
+    ) -> ChorusResult<Emoji> {
+        let url = format!(
+            "{}/guilds/{}/emojis/{}",
+            user.belongs_to.borrow().urls.api,
+            guild_id,
+            emoji_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Emoji>(request, user, crate::api::limits::LimitType::Guild).await
+    }
+
+    /// Creates a custom emoji in a guild. Requires the `MANAGE_EMOJIS_AND_STICKERS` permission.
+    /// # Errors
+    /// * [`crate::errors::ChorusLibError::ValidationError`] - If the name is invalid, or the image
+    ///   is larger than [`crate::types::MAX_EMOJI_IMAGE_SIZE`].
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/emoji#create-guild-emoji](https://discord.com/developers/docs/resources/emoji#create-guild-emoji)
+    pub async fn create(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        schema: &EmojiCreateSchema,
+    ) -> ChorusResult<Emoji> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/guilds/{}/emojis",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Emoji>(request, user, crate::api::limits::LimitType::Guild).await
+    }
+
+    /// Renames a custom emoji or changes the roles allowed to use it. Requires the
+    /// `MANAGE_EMOJIS_AND_STICKERS` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/emoji#modify-guild-emoji](https://discord.com/developers/docs/resources/emoji#modify-guild-emoji)
+    pub async fn modify(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        emoji_id: Snowflake,
+        schema: &EmojiModifySchema,
+    ) -> ChorusResult<Emoji> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/guilds/{}/emojis/{}",
+            user.belongs_to.borrow().urls.api,
+            guild_id,
+            emoji_id
+        );
+        let request = Client::new()
+            .patch(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Emoji>(request, user, crate::api::limits::LimitType::Guild).await
+    }
+
+    /// Deletes a custom emoji. Requires the `MANAGE_EMOJIS_AND_STICKERS` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/emoji#delete-guild-emoji](https://discord.com/developers/docs/resources/emoji#delete-guild-emoji)
+    pub async fn delete(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        emoji_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/emojis/{}",
+            user.belongs_to.borrow().urls.api,
+            guild_id,
+            emoji_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Guild).await
+    }
+}
@@ -35,6 +35,7 @@ impl Guild {
         user: &mut UserMeta,
         guild_create_schema: GuildCreateSchema,
     ) -> ChorusResult<Guild> {
+        user.belongs_to.borrow().validate(&guild_create_schema)?;
         let url = format!("{}/guilds/", user.belongs_to.borrow().urls.api);
         let request = reqwest::Client::new()
             .post(url.clone())
@@ -79,6 +80,21 @@ impl Guild {
         handle_request_as_result(request, user, crate::api::limits::LimitType::Guild).await
     }
 
+    /// Leaves a guild. The owner of a guild cannot leave it, but has to delete it or transfer
+    /// its ownership first.
+    ///
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/user#leave-guild](https://discord.com/developers/docs/resources/user#leave-guild)
+    pub async fn leave(user: &mut UserMeta, guild_id: Snowflake) -> ChorusResult<()> {
+        let url = format!(
+            "{}/users/@me/guilds/{}",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Guild).await
+    }
+
     /// Sends a request to create a new channel in the guild.
     ///
     /// # Arguments
@@ -225,6 +241,7 @@ impl Channel {
         limits_user: &mut Limits,
         instance: &mut Instance,
     ) -> ChorusResult<Channel> {
+        instance.validate(&schema)?;
         let request = Client::new()
             .post(format!(
                 "{}/guilds/{}/channels/",
@@ -1,7 +1,13 @@
+pub use emojis::*;
 pub use guilds::*;
 pub use roles::*;
 pub use roles::*;
+pub use scheduled_events::*;
+pub use stickers::*;
 
+pub mod emojis;
 pub mod guilds;
 pub mod member;
 pub mod roles;
+pub mod scheduled_events;
+pub mod stickers;
@@ -101,6 +101,7 @@ impl types::RoleObject {
             user.belongs_to.borrow().urls.api,
             guild_id
         );
+        user.belongs_to.borrow().validate(&role_create_schema)?;
         let body = to_string::<RoleCreateModifySchema>(&role_create_schema).map_err(|e| {
             ChorusLibError::FormCreationError {
                 error: e.to_string(),
@@ -176,6 +177,7 @@ impl types::RoleObject {
             guild_id,
             role_id
         );
+        user.belongs_to.borrow().validate(&role_create_schema)?;
         let body = to_string::<RoleCreateModifySchema>(&role_create_schema).map_err(|e| {
             ChorusLibError::FormCreationError {
                 error: e.to_string(),
@@ -0,0 +1,214 @@
+use reqwest::Client;
+use serde_json::to_string;
+
+use crate::api::{deserialize_response, handle_request_as_result};
+use crate::errors::{ChorusLibError, ChorusResult};
+use crate::instance::UserMeta;
+use crate::types::{
+    GetScheduledEventUsersSchema, GuildScheduledEvent, GuildScheduledEventCreateSchema,
+    GuildScheduledEventModifySchema, GuildScheduledEventStatus, GuildScheduledEventUser, Snowflake,
+    ValidationError,
+};
+
+impl GuildScheduledEvent {
+    /// Fetches the scheduled events of a guild which have not ended yet.
+    /// # Arguments
+    /// * `with_user_count` - Whether to include the number of subscribed users of each event.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/guild-scheduled-event#list-scheduled-events-for-guild](https://discord.com/developers/docs/resources/guild-scheduled-event#list-scheduled-events-for-guild)
+    pub async fn get_all(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        with_user_count: bool,
+    ) -> ChorusResult<Vec<GuildScheduledEvent>> {
+        let url = format!(
+            "{}/guilds/{}/scheduled-events",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let request = Client::new()
+            .get(url)
+            .bearer_auth(user.token())
+            .query(&[("with_user_count", with_user_count)]);
+        deserialize_response::<Vec<GuildScheduledEvent>>(
+            request,
+            user,
+            crate::api::limits::LimitType::Guild,
+        )
+        .await
+    }
+
+    /// Fetches a scheduled event of a guild.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event](https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event)
+    pub async fn get(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        event_id: Snowflake,
+        with_user_count: bool,
+    ) -> ChorusResult<GuildScheduledEvent> {
+        let url = format!(
+            "{}/guilds/{}/scheduled-events/{}",
+            user.belongs_to.borrow().urls.api,
+            guild_id,
+            event_id
+        );
+        let request = Client::new()
+            .get(url)
+            .bearer_auth(user.token())
+            .query(&[("with_user_count", with_user_count)]);
+        deserialize_response::<GuildScheduledEvent>(
+            request,
+            user,
+            crate::api::limits::LimitType::Guild,
+        )
+        .await
+    }
+
+    /// Schedules an event in a guild. Requires the `MANAGE_EVENTS` permission.
+    /// # Errors
+    /// * [`ChorusLibError::ValidationError`] - If a field required by the entity type of the
+    ///   event is missing, or the event ends before it starts.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event](https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event)
+    pub async fn create(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        schema: &GuildScheduledEventCreateSchema,
+    ) -> ChorusResult<GuildScheduledEvent> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/guilds/{}/scheduled-events",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<GuildScheduledEvent>(
+            request,
+            user,
+            crate::api::limits::LimitType::Guild,
+        )
+        .await
+    }
+
+    /// Edits a scheduled event or changes its status. Requires the `MANAGE_EVENTS` permission.
+    ///
+    /// To change the status of an event, prefer [`GuildScheduledEvent::start`],
+    /// [`GuildScheduledEvent::end`] and [`GuildScheduledEvent::cancel`], which check that the
+    /// transition is allowed.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/guild-scheduled-event#modify-guild-scheduled-event](https://discord.com/developers/docs/resources/guild-scheduled-event#modify-guild-scheduled-event)
+    pub async fn modify(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        event_id: Snowflake,
+        schema: &GuildScheduledEventModifySchema,
+    ) -> ChorusResult<GuildScheduledEvent> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/guilds/{}/scheduled-events/{}",
+            user.belongs_to.borrow().urls.api,
+            guild_id,
+            event_id
+        );
+        let request = Client::new()
+            .patch(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<GuildScheduledEvent>(
+            request,
+            user,
+            crate::api::limits::LimitType::Guild,
+        )
+        .await
+    }
+
+    /// Starts a scheduled event.
+    /// # Errors
+    /// * [`ChorusLibError::ValidationError`] - If the event is not scheduled.
+    pub async fn start(&self, user: &mut UserMeta) -> ChorusResult<GuildScheduledEvent> {
+        self.set_status(user, GuildScheduledEventStatus::Active)
+            .await
+    }
+
+    /// Ends an active event.
+    /// # Errors
+    /// * [`ChorusLibError::ValidationError`] - If the event is not active.
+    pub async fn end(&self, user: &mut UserMeta) -> ChorusResult<GuildScheduledEvent> {
+        self.set_status(user, GuildScheduledEventStatus::Completed)
+            .await
+    }
+
+    /// Cancels a scheduled event which has not started yet.
+    /// # Errors
+    /// * [`ChorusLibError::ValidationError`] - If the event is not scheduled.
+    pub async fn cancel(&self, user: &mut UserMeta) -> ChorusResult<GuildScheduledEvent> {
+        self.set_status(user, GuildScheduledEventStatus::Canceled)
+            .await
+    }
+
+    async fn set_status(
+        &self,
+        user: &mut UserMeta,
+        status: GuildScheduledEventStatus,
+    ) -> ChorusResult<GuildScheduledEvent> {
+        if !self.status.can_transition_to(status) {
+            return Err(ChorusLibError::ValidationError {
+                error: ValidationError::Invalid {
+                    field: "status",
+                    reason: format!("cannot change from {:?} to {:?}", self.status, status),
+                },
+            });
+        }
+        let schema = GuildScheduledEventModifySchema::status(status);
+        GuildScheduledEvent::modify(user, self.guild_id, self.id, &schema).await
+    }
+
+    /// Deletes a scheduled event. Requires the `MANAGE_EVENTS` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/guild-scheduled-event#delete-guild-scheduled-event](https://discord.com/developers/docs/resources/guild-scheduled-event#delete-guild-scheduled-event)
+    pub async fn delete(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        event_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/scheduled-events/{}",
+            user.belongs_to.borrow().urls.api,
+            guild_id,
+            event_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Guild).await
+    }
+
+    /// Fetches the users subscribed to a scheduled event.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event-users](https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event-users)
+    pub async fn users(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        event_id: Snowflake,
+        query: &GetScheduledEventUsersSchema,
+    ) -> ChorusResult<Vec<GuildScheduledEventUser>> {
+        let url = format!(
+            "{}/guilds/{}/scheduled-events/{}/users",
+            user.belongs_to.borrow().urls.api,
+            guild_id,
+            event_id
+        );
+        let request = Client::new()
+            .get(url)
+            .bearer_auth(user.token())
+            .query(query);
+        deserialize_response::<Vec<GuildScheduledEventUser>>(
+            request,
+            user,
+            crate::api::limits::LimitType::Guild,
+        )
+        .await
+    }
+}
@@ -0,0 +1,156 @@
+use reqwest::{multipart, Client};
+use serde::Deserialize;
+use serde_json::to_string;
+
+use crate::api::{deserialize_response, handle_request_as_result};
+use crate::errors::{ChorusLibError, ChorusResult};
+use crate::instance::UserMeta;
+use crate::types::{Snowflake, Sticker, StickerCreateSchema, StickerModifySchema, StickerPack};
+
+impl Sticker {
+    /// Fetches a sticker by its id. Unlike [`Sticker::get`], this works for standard stickers
+    /// and stickers of guilds the user is not a member of.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/sticker#get-sticker](https://discord.com/developers/docs/resources/sticker#get-sticker)
+    pub async fn get_by_id(user: &mut UserMeta, sticker_id: Snowflake) -> ChorusResult<Sticker> {
+        let url = format!(
+            "{}/stickers/{}",
+            user.belongs_to.borrow().urls.api,
+            sticker_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Sticker>(request, user, crate::api::limits::LimitType::Global).await
+    }
+
+    /// Fetches the stickers of a guild.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/sticker#list-guild-stickers](https://discord.com/developers/docs/resources/sticker#list-guild-stickers)
+    pub async fn get_all(user: &mut UserMeta, guild_id: Snowflake) -> ChorusResult<Vec<Sticker>> {
+        let url = format!(
+            "{}/guilds/{}/stickers",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Vec<Sticker>>(request, user, crate::api::limits::LimitType::Guild)
+            .await
+    }
+
+    /// Fetches a sticker of a guild.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/sticker#get-guild-sticker](https://discord.com/developers/docs/resources/sticker#get-guild-sticker)
+    pub async fn get(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        sticker_id: Snowflake,
+    ) -> ChorusResult<Sticker> {
+        let url = format!(
+            "{}/guilds/{}/stickers/{}",
+            user.belongs_to.borrow().urls.api,
+            guild_id,
+            sticker_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Sticker>(request, user, crate::api::limits::LimitType::Guild).await
+    }
+
+    /// Uploads a sticker to a guild. Requires the `MANAGE_EMOJIS_AND_STICKERS` permission.
+    /// # Errors
+    /// * [`ChorusLibError::ValidationError`] - If the name, description or tags are too long, or
+    ///   the file is not a PNG, APNG, GIF or Lottie JSON file of at most
+    ///   [`crate::types::MAX_STICKER_FILE_SIZE`] bytes.
+    /// * [`ChorusLibError::MultipartCreationError`] - If the file cannot be opened.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/sticker#create-guild-sticker](https://discord.com/developers/docs/resources/sticker#create-guild-sticker)
+    pub async fn create(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        schema: StickerCreateSchema,
+    ) -> ChorusResult<Sticker> {
+        user.belongs_to.borrow().validate(&schema)?;
+        let url = format!(
+            "{}/guilds/{}/stickers",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let file = schema.file.into_part().await.map_err(|error| {
+            ChorusLibError::MultipartCreationError {
+                error: error.to_string(),
+            }
+        })?;
+        let form = multipart::Form::new()
+            .text("name", schema.name)
+            .text("description", schema.description.unwrap_or_default())
+            .text("tags", schema.tags)
+            .part("file", file);
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .multipart(form);
+        deserialize_response::<Sticker>(request, user, crate::api::limits::LimitType::Guild).await
+    }
+
+    /// Changes the name, description or tags of a sticker of a guild. Requires the
+    /// `MANAGE_EMOJIS_AND_STICKERS` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/sticker#modify-guild-sticker](https://discord.com/developers/docs/resources/sticker#modify-guild-sticker)
+    pub async fn modify(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        sticker_id: Snowflake,
+        schema: &StickerModifySchema,
+    ) -> ChorusResult<Sticker> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/guilds/{}/stickers/{}",
+            user.belongs_to.borrow().urls.api,
+            guild_id,
+            sticker_id
+        );
+        let request = Client::new()
+            .patch(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Sticker>(request, user, crate::api::limits::LimitType::Guild).await
+    }
+
+    /// Deletes a sticker of a guild. Requires the `MANAGE_EMOJIS_AND_STICKERS` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/sticker#delete-guild-sticker](https://discord.com/developers/docs/resources/sticker#delete-guild-sticker)
+    pub async fn delete(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+        sticker_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/stickers/{}",
+            user.belongs_to.borrow().urls.api,
+            guild_id,
+            sticker_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Guild).await
+    }
+}
+
+#[derive(Deserialize)]
+struct StickerPacksResponse {
+    sticker_packs: Vec<StickerPack>,
+}
+
+impl StickerPack {
+    /// Fetches the packs of standard stickers available to everyone.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/sticker#list-sticker-packs](https://discord.com/developers/docs/resources/sticker#list-sticker-packs)
+    pub async fn get_all(user: &mut UserMeta) -> ChorusResult<Vec<StickerPack>> {
+        let url = format!("{}/sticker-packs", user.belongs_to.borrow().urls.api);
+        let request = Client::new().get(url).bearer_auth(user.token());
+        let response = deserialize_response::<StickerPacksResponse>(
+            request,
+            user,
+            crate::api::limits::LimitType::Global,
+        )
+        .await?;
+        Ok(response.sticker_packs)
+    }
+}
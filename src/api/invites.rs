@@ -0,0 +1,123 @@
+use reqwest::Client;
+use serde_json::to_string;
+
+use crate::api::{deserialize_response, handle_request_as_result};
+use crate::errors::ChorusResult;
+use crate::instance::UserMeta;
+use crate::types::{Channel, CreateChannelInviteSchema, Guild, GuildInvite, Snowflake};
+
+impl GuildInvite {
+    /// Resolves an invite code.
+    /// # Arguments
+    /// * `with_counts` - Whether to include the approximate member and presence counts of the
+    ///   guild.
+    /// # Errors
+    /// * [`crate::errors::ChorusLibError::ReceivedErrorCodeError`] - If the invite does not exist
+    ///   or has expired.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/invite#get-invite](https://discord.com/developers/docs/resources/invite#get-invite)
+    pub async fn get(
+        user: &mut UserMeta,
+        code: &str,
+        with_counts: bool,
+    ) -> ChorusResult<GuildInvite> {
+        let url = format!("{}/invites/{}", user.belongs_to.borrow().urls.api, code);
+        let request = Client::new()
+            .get(url)
+            .bearer_auth(user.token())
+            .query(&[("with_counts", with_counts)]);
+        deserialize_response::<GuildInvite>(request, user, crate::api::limits::LimitType::Global)
+            .await
+    }
+
+    /// Accepts an invite, joining the guild it belongs to.
+    /// # Errors
+    /// * [`crate::errors::ChorusLibError::ReceivedErrorCodeError`] - If the invite does not exist
+    ///   or has expired.
+    /// # Reference
+    /// See [https://docs.spacebar.chat/routes/#post-/invites/-code-/](https://docs.spacebar.chat/routes/#post-/invites/-code-/)
+    pub async fn accept(user: &mut UserMeta, code: &str) -> ChorusResult<GuildInvite> {
+        let url = format!("{}/invites/{}", user.belongs_to.borrow().urls.api, code);
+        let request = Client::new().post(url).bearer_auth(user.token()).body("{}");
+        deserialize_response::<GuildInvite>(request, user, crate::api::limits::LimitType::Guild)
+            .await
+    }
+
+    /// Deletes an invite. Requires the `MANAGE_CHANNELS` permission in the channel of the
+    /// invite, or `MANAGE_GUILD` to delete any invite of the guild.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/invite#delete-invite](https://discord.com/developers/docs/resources/invite#delete-invite)
+    pub async fn delete(user: &mut UserMeta, code: &str) -> ChorusResult<()> {
+        let url = format!("{}/invites/{}", user.belongs_to.borrow().urls.api, code);
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, crate::api::limits::LimitType::Guild).await
+    }
+}
+
+impl Channel {
+    /// Creates an invite to a channel. Requires the `CREATE_INSTANT_INVITE` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#create-channel-invite](https://discord.com/developers/docs/resources/channel#create-channel-invite)
+    pub async fn create_invite(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        schema: &CreateChannelInviteSchema,
+    ) -> ChorusResult<GuildInvite> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/channels/{}/invites",
+            user.belongs_to.borrow().urls.api,
+            channel_id
+        );
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<GuildInvite>(request, user, crate::api::limits::LimitType::Channel)
+            .await
+    }
+
+    /// Fetches the invites to a channel. Requires the `MANAGE_CHANNELS` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/channel#get-channel-invites](https://discord.com/developers/docs/resources/channel#get-channel-invites)
+    pub async fn invites(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+    ) -> ChorusResult<Vec<GuildInvite>> {
+        let url = format!(
+            "{}/channels/{}/invites",
+            user.belongs_to.borrow().urls.api,
+            channel_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Vec<GuildInvite>>(
+            request,
+            user,
+            crate::api::limits::LimitType::Channel,
+        )
+        .await
+    }
+}
+
+impl Guild {
+    /// Fetches the invites to all channels of a guild. Requires the `MANAGE_GUILD` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/guild#get-guild-invites](https://discord.com/developers/docs/resources/guild#get-guild-invites)
+    pub async fn invites(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+    ) -> ChorusResult<Vec<GuildInvite>> {
+        let url = format!(
+            "{}/guilds/{}/invites",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Vec<GuildInvite>>(
+            request,
+            user,
+            crate::api::limits::LimitType::Guild,
+        )
+        .await
+    }
+}
@@ -1,12 +1,18 @@
+pub use cdn::*;
 pub use channels::messages::*;
 pub use common::*;
 pub use guilds::*;
+pub use invites::*;
 pub use policies::instance::instance::*;
 pub use policies::instance::limits::*;
+pub use webhooks::*;
 
 pub mod auth;
+pub mod cdn;
 pub mod channels;
 pub mod common;
 pub mod guilds;
+pub mod invites;
 pub mod policies;
 pub mod users;
+pub mod webhooks;
@@ -0,0 +1,72 @@
+use crate::api::common::get_json;
+use crate::errors::ChorusResult;
+use crate::instance::Instance;
+use crate::types::{GatewayUrlResponse, InstanceDomainsResponse, WellKnownResponse};
+use crate::UrlBundle;
+
+impl Instance {
+    /// Discovers the Spacebar instance running on `domain` and connects to it.
+    ///
+    /// See [`Instance::discover_urls`] for how the URLs of the instance are found.
+    ///
+    /// # Arguments
+    /// * `domain` - The domain of the instance, e.g. `"example.org"`. If no protocol is given,
+    ///   HTTPS is assumed.
+    /// # Errors
+    /// * [`crate::errors::ChorusLibError`] - If the instance could not be discovered or connected
+    ///   to.
+    pub async fn discover(domain: &str) -> ChorusResult<Instance> {
+        let urls = Instance::discover_urls(domain).await?;
+        Instance::new(urls).await
+    }
+
+    /// Finds the API, gateway and CDN URLs of the Spacebar instance running on `domain`.
+    ///
+    /// The API URL is read from `/.well-known/spacebar` on the domain. The CDN and gateway URLs
+    /// are then read from `/policies/instance/domains`. If the instance does not announce a
+    /// gateway there, `/gateway` is asked instead.
+    ///
+    /// # Errors
+    /// * [`InvalidUrlError`](crate::errors::ChorusLibError::InvalidUrlError) - If `domain` or one
+    ///   of the discovered URLs is invalid.
+    /// * [`RequestErrorError`](crate::errors::ChorusLibError::RequestErrorError) - If one of the
+    ///   requests could not be sent.
+    /// * [`ReceivedErrorCodeError`](crate::errors::ChorusLibError::ReceivedErrorCodeError) - If
+    ///   the server did not respond with a success status code, e.g. because the domain does not
+    ///   host a Spacebar instance.
+    /// * [`InvalidResponseError`](crate::errors::ChorusLibError::InvalidResponseError) - If one of
+    ///   the responses could not be parsed.
+    pub async fn discover_urls(domain: &str) -> ChorusResult<UrlBundle> {
+        let domain = if domain.contains("://") {
+            domain.to_string()
+        } else {
+            format!("https://{}", domain)
+        };
+        let root = UrlBundle::try_parse_url(domain)?;
+
+        let well_known: WellKnownResponse = get_json(root + "/.well-known/spacebar").await?;
+        let api = UrlBundle::try_parse_url(well_known.api)?;
+
+        let domains = Instance::instance_domains(&api).await?;
+        let wss = match domains.gateway {
+            Some(gateway) => gateway,
+            None => Instance::gateway_url(&api).await?,
+        };
+        UrlBundle::try_new(api, wss, domains.cdn)
+    }
+
+    /// Gets the URLs the instance with the given API URL is reachable at.
+    /// # Reference
+    /// See <https://docs.spacebar.chat/routes/#get-/policies/instance/domains/>
+    pub async fn instance_domains(api_url: &str) -> ChorusResult<InstanceDomainsResponse> {
+        get_json(format!("{}/policies/instance/domains", api_url)).await
+    }
+
+    /// Gets the URL of the gateway of the instance with the given API URL.
+    /// # Reference
+    /// See <https://discord-userdoccers.vercel.app/topics/gateway#get-gateway>
+    pub async fn gateway_url(api_url: &str) -> ChorusResult<String> {
+        let response: GatewayUrlResponse = get_json(format!("{}/gateway", api_url)).await?;
+        Ok(response.url)
+    }
+}
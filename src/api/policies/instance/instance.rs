@@ -1,9 +1,10 @@
 use reqwest::Client;
 use serde_json::from_str;
 
+use crate::api::common::get_json;
 use crate::errors::{ChorusLibError, ChorusResult};
 use crate::instance::Instance;
-use crate::types::GeneralConfiguration;
+use crate::types::{GeneralConfiguration, LimitsConfiguration};
 
 impl Instance {
     /// Gets the instance policies schema.
@@ -31,4 +32,11 @@ impl Instance {
         let body = request.text().await.unwrap();
         Ok(from_str::<GeneralConfiguration>(&body).unwrap())
     }
+
+    /// Gets the limits of the instance, like the maximum length of messages and usernames.
+    /// # Errors
+    /// [`ChorusLibError`] - If the request fails.
+    pub async fn limits_configuration(&self) -> ChorusResult<LimitsConfiguration> {
+        get_json(self.urls.api.clone() + "/policies/instance/limits").await
+    }
 }
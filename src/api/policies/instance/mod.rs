@@ -1,5 +1,7 @@
+pub use domains::*;
 pub use instance::*;
 pub use limits::*;
 
+pub mod domains;
 pub mod instance;
 pub mod limits;
@@ -0,0 +1,110 @@
+use reqwest::Client;
+use serde_json::to_string;
+
+use crate::{
+    api::deserialize_response,
+    errors::ChorusResult,
+    instance::{Token, UserMeta},
+    types::{
+        BackupCode, BackupCodesResponse, BackupCodesSchema, TotpDisableSchema, TotpEnableResponse,
+        TotpEnableSchema,
+    },
+};
+
+impl UserMeta {
+    /// Enables two-factor authentication for the current user. The new token returned by the
+    /// server replaces the current token of this [`UserMeta`].
+    ///
+    /// # Returns
+    ///
+    /// The backup codes of the user. Empty, if the instance does not generate backup codes.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::errors::ChorusLibError`] - If the password or code is wrong, or the request
+    ///   fails.
+    ///
+    /// # Reference
+    /// See <https://docs.spacebar.chat/routes/#post-/users/@me/mfa/totp/enable/>
+    pub async fn enable_totp(&mut self, schema: TotpEnableSchema) -> ChorusResult<Vec<BackupCode>> {
+        let request = Client::new()
+            .post(format!(
+                "{}/users/@me/mfa/totp/enable/",
+                self.belongs_to.borrow().urls.api
+            ))
+            .body(to_string(&schema).unwrap())
+            .bearer_auth(self.token());
+        let response = deserialize_response::<TotpEnableResponse>(
+            request,
+            self,
+            crate::api::limits::LimitType::Global,
+        )
+        .await?;
+        self.set_token(response.token);
+        self.object.mfa_enabled = Some(true);
+        Ok(response.backup_codes)
+    }
+
+    /// Disables two-factor authentication for the current user. The new token returned by the
+    /// server replaces the current token of this [`UserMeta`].
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - A TOTP or backup code.
+    ///
+    /// # Reference
+    /// See <https://docs.spacebar.chat/routes/#post-/users/@me/mfa/totp/disable/>
+    pub async fn disable_totp(&mut self, code: &str) -> ChorusResult<()> {
+        let schema = TotpDisableSchema {
+            code: code.to_string(),
+        };
+        let request = Client::new()
+            .post(format!(
+                "{}/users/@me/mfa/totp/disable/",
+                self.belongs_to.borrow().urls.api
+            ))
+            .body(to_string(&schema).unwrap())
+            .bearer_auth(self.token());
+        let response =
+            deserialize_response::<Token>(request, self, crate::api::limits::LimitType::Global)
+                .await?;
+        self.set_token(response.token);
+        self.object.mfa_enabled = Some(false);
+        Ok(())
+    }
+
+    /// Gets the backup codes of the current user.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The password of the current user.
+    /// * `regenerate` - Whether to invalidate all previous backup codes and generate new ones.
+    ///   Has no effect if the instance does not generate backup codes.
+    ///
+    /// # Reference
+    /// See <https://docs.spacebar.chat/routes/#post-/users/@me/mfa/codes/>
+    pub async fn get_backup_codes(
+        &mut self,
+        password: &str,
+        regenerate: bool,
+    ) -> ChorusResult<Vec<BackupCode>> {
+        let schema = BackupCodesSchema {
+            password: password.to_string(),
+            regenerate,
+        };
+        let request = Client::new()
+            .post(format!(
+                "{}/users/@me/mfa/codes/",
+                self.belongs_to.borrow().urls.api
+            ))
+            .body(to_string(&schema).unwrap())
+            .bearer_auth(self.token());
+        let response = deserialize_response::<BackupCodesResponse>(
+            request,
+            self,
+            crate::api::limits::LimitType::Global,
+        )
+        .await?;
+        Ok(response.backup_codes)
+    }
+}
@@ -1,5 +1,7 @@
+pub use mfa::*;
 pub use relationships::*;
 pub use users::*;
 
+pub mod mfa;
 pub mod relationships;
 pub mod users;
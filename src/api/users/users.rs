@@ -6,7 +6,7 @@ use crate::{
     errors::{ChorusLibError, ChorusResult},
     instance::{Instance, UserMeta},
     limit::LimitedRequester,
-    types::{User, UserModifySchema, UserSettings},
+    types::{User, UserModifyResponse, UserModifySchema, UserSettings},
 };
 
 impl UserMeta {
@@ -36,6 +36,9 @@ impl UserMeta {
 
     /// Modify the current user's `UserObject`.
     ///
+    /// If the password of the user is changed, the server invalidates the current token. The new
+    /// token is applied to this [`UserMeta`].
+    ///
     /// # Arguments
     ///
     /// * `modify_schema` - A `UserModifySchema` object containing the fields to modify.
@@ -44,22 +47,53 @@ impl UserMeta {
     ///
     /// Returns an `ChorusLibError` if the request fails or if a password is required but not provided.
     pub async fn modify(&mut self, modify_schema: UserModifySchema) -> ChorusResult<User> {
-        if modify_schema.new_password.is_some()
+        if (modify_schema.new_password.is_some()
             || modify_schema.email.is_some()
-            || modify_schema.code.is_some()
+            || modify_schema.code.is_some())
+            && modify_schema.current_password.is_none()
         {
             return Err(ChorusLibError::PasswordRequiredError);
         }
+        self.belongs_to.borrow().validate(&modify_schema)?;
         let request = Client::new()
             .patch(format!("{}/users/@me/", self.belongs_to.borrow().urls.api))
             .body(to_string(&modify_schema).unwrap())
             .bearer_auth(self.token());
-        let user_updated =
-            deserialize_response::<User>(request, self, crate::api::limits::LimitType::Ip)
-                .await
-                .unwrap();
-        let _ = std::mem::replace(&mut self.object, user_updated.clone());
-        Ok(user_updated)
+        let response = deserialize_response::<UserModifyResponse>(
+            request,
+            self,
+            crate::api::limits::LimitType::Ip,
+        )
+        .await?;
+        if let Some(token) = response.token {
+            self.set_token(token);
+        }
+        let _ = std::mem::replace(&mut self.object, response.user.clone());
+        Ok(response.user)
+    }
+
+    /// Changes the password of the current user, and applies the new token the server responds
+    /// with.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_password` - The current password of the user.
+    /// * `new_password` - The password to change to.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChorusLibError`] - If `current_password` is wrong, or the request fails otherwise.
+    pub async fn change_password(
+        &mut self,
+        current_password: &str,
+        new_password: &str,
+    ) -> ChorusResult<User> {
+        let modify_schema = UserModifySchema {
+            current_password: Some(current_password.to_string()),
+            new_password: Some(new_password.to_string()),
+            ..Default::default()
+        };
+        self.modify(modify_schema).await
     }
 
     /// Sends a request to the server which deletes the user from the Instance.
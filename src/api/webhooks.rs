@@ -0,0 +1,302 @@
+use reqwest::{Client, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde_json::{from_str, to_string};
+
+use crate::api::common::{multipart_form, validate_message};
+use crate::api::limits::LimitType;
+use crate::api::{deserialize_response, handle_request_as_result};
+use crate::errors::{ChorusLibError, ChorusResult};
+use crate::instance::{Instance, UserMeta};
+use crate::limit::LimitedRequester;
+use crate::types::{
+    FileUpload, Message, MessageModifySchema, Snowflake, ValidationError, Webhook,
+    WebhookCreateSchema, WebhookExecuteQuery, WebhookExecuteSchema, WebhookModifySchema,
+};
+
+impl Webhook {
+    /// Creates an incoming webhook in a channel. Requires the `MANAGE_WEBHOOKS` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#create-webhook](https://discord.com/developers/docs/resources/webhook#create-webhook)
+    pub async fn create(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+        schema: &WebhookCreateSchema,
+    ) -> ChorusResult<Webhook> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/channels/{}/webhooks",
+            user.belongs_to.borrow().urls.api,
+            channel_id
+        );
+        let request = Client::new()
+            .post(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Webhook>(request, user, LimitType::Webhook).await
+    }
+
+    /// Fetches the webhooks of a channel. Requires the `MANAGE_WEBHOOKS` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#get-channel-webhooks](https://discord.com/developers/docs/resources/webhook#get-channel-webhooks)
+    pub async fn channel_webhooks(
+        user: &mut UserMeta,
+        channel_id: Snowflake,
+    ) -> ChorusResult<Vec<Webhook>> {
+        let url = format!(
+            "{}/channels/{}/webhooks",
+            user.belongs_to.borrow().urls.api,
+            channel_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Vec<Webhook>>(request, user, LimitType::Webhook).await
+    }
+
+    /// Fetches the webhooks of all channels of a guild. Requires the `MANAGE_WEBHOOKS`
+    /// permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#get-guild-webhooks](https://discord.com/developers/docs/resources/webhook#get-guild-webhooks)
+    pub async fn guild_webhooks(
+        user: &mut UserMeta,
+        guild_id: Snowflake,
+    ) -> ChorusResult<Vec<Webhook>> {
+        let url = format!(
+            "{}/guilds/{}/webhooks",
+            user.belongs_to.borrow().urls.api,
+            guild_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Vec<Webhook>>(request, user, LimitType::Webhook).await
+    }
+
+    /// Fetches a webhook. To fetch a webhook without a user, see
+    /// [`Instance::get_webhook_with_token`].
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#get-webhook](https://discord.com/developers/docs/resources/webhook#get-webhook)
+    pub async fn get(user: &mut UserMeta, webhook_id: Snowflake) -> ChorusResult<Webhook> {
+        let url = format!(
+            "{}/webhooks/{}",
+            user.belongs_to.borrow().urls.api,
+            webhook_id
+        );
+        let request = Client::new().get(url).bearer_auth(user.token());
+        deserialize_response::<Webhook>(request, user, LimitType::Webhook).await
+    }
+
+    /// Edits a webhook, or moves it to another channel. Requires the `MANAGE_WEBHOOKS`
+    /// permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#modify-webhook](https://discord.com/developers/docs/resources/webhook#modify-webhook)
+    pub async fn modify(
+        user: &mut UserMeta,
+        webhook_id: Snowflake,
+        schema: &WebhookModifySchema,
+    ) -> ChorusResult<Webhook> {
+        user.belongs_to.borrow().validate(schema)?;
+        let url = format!(
+            "{}/webhooks/{}",
+            user.belongs_to.borrow().urls.api,
+            webhook_id
+        );
+        let request = Client::new()
+            .patch(url)
+            .bearer_auth(user.token())
+            .body(to_string(schema).unwrap());
+        deserialize_response::<Webhook>(request, user, LimitType::Webhook).await
+    }
+
+    /// Deletes a webhook. Requires the `MANAGE_WEBHOOKS` permission.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#delete-webhook](https://discord.com/developers/docs/resources/webhook#delete-webhook)
+    pub async fn delete(user: &mut UserMeta, webhook_id: Snowflake) -> ChorusResult<()> {
+        let url = format!(
+            "{}/webhooks/{}",
+            user.belongs_to.borrow().urls.api,
+            webhook_id
+        );
+        let request = Client::new().delete(url).bearer_auth(user.token());
+        handle_request_as_result(request, user, LimitType::Webhook).await
+    }
+}
+
+/// Requests authenticated by the token of a webhook, which do not need a logged in user.
+impl Instance {
+    /// Fetches a webhook using its token.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#get-webhook-with-token](https://discord.com/developers/docs/resources/webhook#get-webhook-with-token)
+    pub async fn get_webhook_with_token(
+        &mut self,
+        webhook_id: Snowflake,
+        token: &str,
+    ) -> ChorusResult<Webhook> {
+        let url = format!("{}/webhooks/{}/{}", self.urls.api, webhook_id, token);
+        let response = self.send_webhook_request(Client::new().get(url)).await?;
+        deserialize_webhook_response(response).await
+    }
+
+    /// Edits a webhook using its token. The channel of the webhook cannot be changed this way.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#modify-webhook-with-token](https://discord.com/developers/docs/resources/webhook#modify-webhook-with-token)
+    pub async fn modify_webhook_with_token(
+        &mut self,
+        webhook_id: Snowflake,
+        token: &str,
+        schema: &WebhookModifySchema,
+    ) -> ChorusResult<Webhook> {
+        if schema.channel_id.is_some() {
+            return Err(ChorusLibError::ValidationError {
+                error: ValidationError::Invalid {
+                    field: "channel_id",
+                    reason: "webhooks can only be moved by a user".to_string(),
+                },
+            });
+        }
+        self.validate(schema)?;
+        let url = format!("{}/webhooks/{}/{}", self.urls.api, webhook_id, token);
+        let request = Client::new().patch(url).body(to_string(schema).unwrap());
+        let response = self.send_webhook_request(request).await?;
+        deserialize_webhook_response(response).await
+    }
+
+    /// Deletes a webhook using its token.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#delete-webhook-with-token](https://discord.com/developers/docs/resources/webhook#delete-webhook-with-token)
+    pub async fn delete_webhook_with_token(
+        &mut self,
+        webhook_id: Snowflake,
+        token: &str,
+    ) -> ChorusResult<()> {
+        let url = format!("{}/webhooks/{}/{}", self.urls.api, webhook_id, token);
+        self.send_webhook_request(Client::new().delete(url)).await?;
+        Ok(())
+    }
+
+    /// Posts a message as a webhook.
+    /// # Arguments
+    /// * `files` - The files to attach to the message. The `attachments` of the message are
+    ///   replaced with their metadata.
+    /// * `query` - Whether to wait for the message to be posted, and the thread to post it in.
+    /// # Returns
+    /// The posted message if [`WebhookExecuteQuery::wait`] is set, [`None`] otherwise.
+    /// # Errors
+    /// * [`ChorusLibError::ValidationError`] - If the message is empty or violates the limits of
+    ///   the instance.
+    /// * [`ChorusLibError::MultipartCreationError`] - If a file cannot be opened.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#execute-webhook](https://discord.com/developers/docs/resources/webhook#execute-webhook)
+    pub async fn execute_webhook(
+        &mut self,
+        webhook_id: Snowflake,
+        token: &str,
+        message: &mut WebhookExecuteSchema,
+        files: Option<Vec<FileUpload>>,
+        query: &WebhookExecuteQuery,
+    ) -> ChorusResult<Option<Message>> {
+        let files = files.unwrap_or_default();
+        if message.content.as_deref().unwrap_or_default().is_empty()
+            && message.embeds.as_deref().unwrap_or_default().is_empty()
+            && message.components.as_deref().unwrap_or_default().is_empty()
+            && files.is_empty()
+        {
+            return Err(ChorusLibError::ValidationError {
+                error: ValidationError::Missing { field: "content" },
+            });
+        }
+        validate_message(self, &*message)?;
+        self.validate(&files)?;
+        if message.allowed_mentions.is_none() {
+            message.allowed_mentions = self.default_allowed_mentions.clone();
+        }
+
+        let url = format!("{}/webhooks/{}/{}", self.urls.api, webhook_id, token);
+        let request = if files.is_empty() {
+            Client::new().post(url).body(to_string(message).unwrap())
+        } else {
+            message.attachments = Some(
+                files
+                    .iter()
+                    .enumerate()
+                    .map(|(index, file)| file.attachment(index as i16))
+                    .collect(),
+            );
+            let form = multipart_form(&*message, files).await?;
+            Client::new().post(url).multipart(form)
+        };
+        let response = self.send_webhook_request(request.query(query)).await?;
+        if !query.wait {
+            return Ok(None);
+        }
+        deserialize_webhook_response(response).await.map(Some)
+    }
+
+    /// Edits a message posted by a webhook.
+    /// # Arguments
+    /// * `thread_id` - The thread the message was posted in, if any.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#edit-webhook-message](https://discord.com/developers/docs/resources/webhook#edit-webhook-message)
+    pub async fn edit_webhook_message(
+        &mut self,
+        webhook_id: Snowflake,
+        token: &str,
+        message_id: Snowflake,
+        schema: &MessageModifySchema,
+        thread_id: Option<Snowflake>,
+    ) -> ChorusResult<Message> {
+        validate_message(self, schema)?;
+        let url = format!(
+            "{}/webhooks/{}/{}/messages/{}",
+            self.urls.api, webhook_id, token, message_id
+        );
+        let request = Client::new()
+            .patch(url)
+            .body(to_string(schema).unwrap())
+            .query(&thread_query(thread_id));
+        let response = self.send_webhook_request(request).await?;
+        deserialize_webhook_response(response).await
+    }
+
+    /// Deletes a message posted by a webhook.
+    /// # Arguments
+    /// * `thread_id` - The thread the message was posted in, if any.
+    /// # Reference
+    /// See [https://discord.com/developers/docs/resources/webhook#delete-webhook-message](https://discord.com/developers/docs/resources/webhook#delete-webhook-message)
+    pub async fn delete_webhook_message(
+        &mut self,
+        webhook_id: Snowflake,
+        token: &str,
+        message_id: Snowflake,
+        thread_id: Option<Snowflake>,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/webhooks/{}/{}/messages/{}",
+            self.urls.api, webhook_id, token, message_id
+        );
+        let request = Client::new().delete(url).query(&thread_query(thread_id));
+        self.send_webhook_request(request).await?;
+        Ok(())
+    }
+
+    async fn send_webhook_request(&mut self, request: RequestBuilder) -> ChorusResult<Response> {
+        // There is no user whose rate limits are affected by a webhook, so a copy of the limits
+        // of the instance is passed as the user's limits instead.
+        let mut cloned_limits = self.limits.clone();
+        LimitedRequester::send_request(request, LimitType::Webhook, self, &mut cloned_limits).await
+    }
+}
+
+fn thread_query(thread_id: Option<Snowflake>) -> Vec<(&'static str, String)> {
+    thread_id
+        .map(|thread_id| vec![("thread_id", thread_id.to_string())])
+        .unwrap_or_default()
+}
+
+async fn deserialize_webhook_response<T: DeserializeOwned>(response: Response) -> ChorusResult<T> {
+    let text = response
+        .text()
+        .await
+        .map_err(|error| ChorusLibError::InvalidResponseError {
+            error: error.to_string(),
+        })?;
+    from_str::<T>(&text).map_err(|error| ChorusLibError::InvalidResponseError {
+        error: error.to_string(),
+    })
+}
@@ -1,5 +1,7 @@
 use custom_error::custom_error;
 
+use crate::types::{CaptchaChallenge, ValidationError};
+
 custom_error! {
     #[derive(PartialEq, Eq)]
     pub FieldFormatError
@@ -27,7 +29,12 @@ custom_error! {
     NotFound{error: String} = "The provided resource hasn't been found: {error}",
     PasswordRequiredError = "You need to provide your current password to authenticate for this action.",
     InvalidResponseError{error: String} = "The response is malformed and cannot be processed. Error: {error}",
-    InvalidArgumentsError{error: String} = "Invalid arguments were provided. Error: {error}"
+    InvalidArgumentsError{error: String} = "Invalid arguments were provided. Error: {error}",
+    GatewayConnectionError{error: String} = "An error occured on the gateway connection: {error}",
+    InvalidUrlError{url: String, error: String} = "The URL {url} is invalid: {error}",
+    MfaRequired{ticket: String} = "Two-factor authentication is required. Finish logging in with a TOTP or backup code and the ticket {ticket}",
+    CaptchaRequired{challenge: CaptchaChallenge} = "The server requires a captcha to be solved: {challenge}",
+    ValidationError{error: ValidationError} = "The request violates the policies of the instance: {error}"
 }
 
 custom_error! {
@@ -145,6 +145,15 @@ impl GatewayMessage {
     }
 }
 
+/// The state of a gateway session, which is needed to resume it later on.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GatewaySessionState {
+    /// The session id we received in the Ready event
+    pub session_id: Option<String>,
+    /// The last sequence number we received from the gateway
+    pub sequence_number: Option<u64>,
+}
+
 /// Represents a handle to a Gateway connection. A Gateway connection will create observable
 /// [`GatewayEvents`](GatewayEvent), which you can subscribe to. Gateway events include all currently
 /// implemented [Types] with the trait [`WebSocketEvent`]
@@ -164,6 +173,7 @@ pub struct GatewayHandle {
     pub handle: JoinHandle<()>,
     /// Tells gateway tasks to close
     kill_send: tokio::sync::broadcast::Sender<()>,
+    session_state: Arc<Mutex<GatewaySessionState>>,
 }
 
 impl GatewayHandle {
@@ -198,6 +208,12 @@ impl GatewayHandle {
 
     /// Sends a resume event to the gateway
     pub async fn send_resume(&self, to_send: types::GatewayResume) {
+        {
+            let mut session_state = self.session_state.lock().await;
+            session_state.session_id = Some(to_send.session_id.clone());
+            session_state.sequence_number = to_send.seq.parse().ok();
+        }
+
         let to_send_value = serde_json::to_value(&to_send).unwrap();
 
         println!("GW: Sending Resume..");
@@ -254,6 +270,12 @@ impl GatewayHandle {
             .await;
     }
 
+    /// Returns the session id and the last sequence number of this connection, which are needed
+    /// to resume the session after the connection has been closed.
+    pub async fn session_state(&self) -> GatewaySessionState {
+        self.session_state.lock().await.clone()
+    }
+
     /// Closes the websocket connection and stops all gateway tasks;
     ///
     /// Esentially pulls the plug on the gateway, leaving it possible to resume;
@@ -276,6 +298,7 @@ pub struct Gateway {
     >,
     pub websocket_receive: SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
     kill_send: tokio::sync::broadcast::Sender<()>,
+    session_state: Arc<Mutex<GatewaySessionState>>,
 }
 
 impl Gateway {
@@ -325,6 +348,7 @@ impl Gateway {
 
         let events = Events::default();
         let shared_events = Arc::new(Mutex::new(events));
+        let shared_session_state = Arc::new(Mutex::new(GatewaySessionState::default()));
 
         let mut gateway = Gateway {
             events: shared_events.clone(),
@@ -336,6 +360,7 @@ impl Gateway {
             websocket_send: shared_websocket_send.clone(),
             websocket_receive,
             kill_send: kill_send.clone(),
+            session_state: shared_session_state.clone(),
         };
 
         // Now we can continuously check for messages in a different task, since we aren't going to receive another hello
@@ -349,6 +374,7 @@ impl Gateway {
             websocket_send: shared_websocket_send.clone(),
             handle,
             kill_send: kill_send.clone(),
+            session_state: shared_session_state,
         })
     }
 
@@ -432,23 +458,45 @@ impl Gateway {
 
                 //println!("Event data dump: {}", gateway_payload.d.clone().unwrap().get());
 
+                // Observers of every dispatched event get notified before the typed event is parsed
+                {
+                    let event = &mut self.events.lock().await.dispatch;
+                    if event.is_observed() {
+                        let event_data = gateway_payload
+                            .event_data
+                            .and_then(|data| serde_json::from_str(data.get()).ok());
+                        event
+                            .notify(types::GatewayDispatch {
+                                event_name: gateway_payload_t.clone(),
+                                sequence_number: gateway_payload.sequence_number,
+                                event_data,
+                            })
+                            .await;
+                    }
+                }
+
                 // See https://discord.com/developers/docs/topics/gateway-events#receive-events
                 // "Some" of these are undocumented
                 match gateway_payload_t.as_str() {
                     "READY" => {
-                        let event = &mut self.events.lock().await.session.ready;
-
-                        let result =
-                            Gateway::handle_event(gateway_payload.event_data.unwrap().get(), event)
-                                .await;
+                        // Parsed here instead of in handle_event, since we need to remember the
+                        // session id in case we want to resume the session later on
+                        let result: Result<types::GatewayReady, serde_json::Error> =
+                            serde_json::from_str(gateway_payload.event_data.unwrap().get());
 
-                        if result.is_err() {
-                            println!(
-                                "Failed to parse gateway event {} ({})",
-                                gateway_payload_t,
-                                result.err().unwrap()
-                            );
-                            return;
+                        match result {
+                            Ok(ready) => {
+                                self.session_state.lock().await.session_id =
+                                    Some(ready.session_id.clone());
+                                self.events.lock().await.session.ready.notify(ready).await;
+                            }
+                            Err(e) => {
+                                println!(
+                                    "Failed to parse gateway event {} ({})",
+                                    gateway_payload_t, e
+                                );
+                                return;
+                            }
                         }
                     }
                     "READY_SUPPLEMENTAL" => {
@@ -1506,6 +1554,8 @@ impl Gateway {
 
         // If we we received a seq number we should let it know
         if gateway_payload.sequence_number.is_some() {
+            self.session_state.lock().await.sequence_number = gateway_payload.sequence_number;
+
             let heartbeat_communication = HeartbeatThreadCommunication {
                 sequence_number: Some(gateway_payload.sequence_number.unwrap()),
                 // Op code is irrelevant here
@@ -1732,6 +1782,8 @@ mod events {
         pub call: Call,
         pub voice: Voice,
         pub webhooks: Webhooks,
+        /// Notified for every dispatched event, in addition to the typed event above
+        pub dispatch: GatewayEvent<types::GatewayDispatch>,
         pub gateway_identify_payload: GatewayEvent<types::GatewayIdentifyPayload>,
         pub gateway_resume: GatewayEvent<types::GatewayResume>,
     }
@@ -1,13 +1,19 @@
 use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
+use std::sync::Arc;
 
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
+use crate::api::auth::{CaptchaSolver, CredentialProvider};
 use crate::api::limits::Limits;
-use crate::errors::{ChorusLibError, ChorusResult, FieldFormatError};
-use crate::types::{GeneralConfiguration, User, UserSettings};
+use crate::errors::{ChorusLibError, ChorusResult, FieldFormatError, GatewayError};
+use crate::gateway::{Gateway, GatewayHandle};
+use crate::types::{
+    self, AllowedMention, GeneralConfiguration, LimitsConfiguration, Snowflake, User, UserSettings,
+    Validate,
+};
 use crate::UrlBundle;
 
 #[derive(Debug, Clone)]
@@ -19,6 +25,14 @@ pub struct Instance {
     pub instance_info: GeneralConfiguration,
     pub limits: Limits,
     pub client: Client,
+    /// Solves captcha challenges when registering or logging in, see [`CaptchaSolver`].
+    pub captcha_solver: Option<Arc<dyn CaptchaSolver>>,
+    /// The limits of the instance, which requests are validated against before they are sent.
+    /// [`None`], if the instance did not publish its limits.
+    pub limits_information: Option<LimitsConfiguration>,
+    /// The allowed mentions used for messages which do not set their own, see
+    /// [`Instance::set_default_allowed_mentions`].
+    pub default_allowed_mentions: Option<AllowedMention>,
 }
 
 impl Instance {
@@ -35,6 +49,9 @@ impl Instance {
             instance_info: GeneralConfiguration::default(),
             limits: Limits::check_limits(urls.api).await,
             client: Client::new(),
+            captcha_solver: None,
+            limits_information: None,
+            default_allowed_mentions: None,
         };
         instance.instance_info = match instance.general_configuration_schema().await {
             Ok(schema) => schema,
@@ -44,8 +61,31 @@ impl Instance {
                 });
             }
         };
+        // Validating requests locally is a convenience. The server validates them either way.
+        instance.limits_information = instance.limits_configuration().await.ok();
         Ok(instance)
     }
+
+    /// Sets the allowed mentions for all messages sent to this instance which do not set their
+    /// own, for example [`AllowedMention::none`] for bots echoing user input. [`None`] leaves
+    /// them to the server, which allows all mentions.
+    pub fn set_default_allowed_mentions(&mut self, allowed_mentions: Option<AllowedMention>) {
+        self.default_allowed_mentions = allowed_mentions;
+    }
+
+    /// Validates a request body against the limits of this instance.
+    ///
+    /// # Errors
+    /// * [`ChorusLibError::ValidationError`] - If the request body violates a limit. Always
+    ///   succeeds, if the limits of the instance are unknown.
+    pub fn validate<T: Validate>(&self, schema: &T) -> ChorusResult<()> {
+        match &self.limits_information {
+            Some(limits) => schema
+                .validate(limits)
+                .map_err(|error| ChorusLibError::ValidationError { error }),
+            None => Ok(()),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -85,6 +125,11 @@ pub struct UserMeta {
     pub limits: Limits,
     pub settings: UserSettings,
     pub object: User,
+    /// The gateway connection of this user, see [`UserMeta::connect_gateway`].
+    pub gateway: Option<GatewayHandle>,
+    /// Provides the credentials to log in again with, once the token of this user expires. See
+    /// [`CredentialProvider`].
+    pub credential_provider: Option<Arc<dyn CredentialProvider>>,
 }
 
 impl UserMeta {
@@ -96,6 +141,89 @@ impl UserMeta {
         self.token = token;
     }
 
+    /// Returns a [`SavedSession`], from which this user can be restored later on using
+    /// [`Instance::restore_account`]. If the user is connected to the gateway, the gateway session
+    /// is saved as well.
+    pub async fn save_session(&self) -> SavedSession {
+        let mut session = SavedSession {
+            urls: self.belongs_to.borrow().urls.clone(),
+            token: self.token(),
+            user_id: self.object.id,
+            settings: self.settings.clone(),
+            gateway_session_id: None,
+            sequence_number: None,
+        };
+        if let Some(gateway) = &self.gateway {
+            let state = gateway.session_state().await;
+            session.gateway_session_id = state.session_id;
+            session.sequence_number = state.sequence_number;
+        }
+        session
+    }
+
+    /// Connects to the gateway of the instance this user belongs to and identifies with the
+    /// users' token.
+    ///
+    /// If the user is already connected, the previous connection is closed and all observers
+    /// subscribed to its events are moved over to the new connection.
+    pub async fn connect_gateway(&mut self) -> Result<(), GatewayError> {
+        let gateway = self.open_gateway().await?;
+
+        let mut identify = types::GatewayIdentifyPayload::common();
+        identify.token = self.token();
+        gateway.send_identify(identify).await;
+
+        self.gateway = Some(gateway);
+        Ok(())
+    }
+
+    /// Resumes the gateway session of a [`SavedSession`] instead of starting a new one. If the
+    /// session has no gateway session to resume, a new one is started using
+    /// [`UserMeta::connect_gateway`].
+    ///
+    /// If the user is already connected, the previous connection is closed and all observers
+    /// subscribed to its events are moved over to the new connection.
+    pub async fn resume_gateway(&mut self, session: &SavedSession) -> Result<(), GatewayError> {
+        let (Some(session_id), Some(sequence_number)) =
+            (&session.gateway_session_id, session.sequence_number)
+        else {
+            return self.connect_gateway().await;
+        };
+        let gateway = self.open_gateway().await?;
+
+        let resume = types::GatewayResume {
+            token: self.token(),
+            session_id: session_id.clone(),
+            seq: sequence_number.to_string(),
+        };
+        gateway.send_resume(resume).await;
+
+        self.gateway = Some(gateway);
+        Ok(())
+    }
+
+    /// Closes the gateway connection of this user, if there is one.
+    pub async fn disconnect_gateway(&mut self) {
+        if let Some(gateway) = self.gateway.take() {
+            gateway.close().await;
+        }
+    }
+
+    /// Opens a new gateway connection, which takes over the observers of the current one. The
+    /// current connection is closed. The new connection still needs to identify or resume.
+    async fn open_gateway(&mut self) -> Result<GatewayHandle, GatewayError> {
+        let websocket_url = self.belongs_to.borrow().urls.wss.clone();
+        let gateway = Gateway::new(websocket_url).await?;
+        if let Some(previous) = self.gateway.take() {
+            std::mem::swap(
+                &mut *gateway.events.lock().await,
+                &mut *previous.events.lock().await,
+            );
+            previous.close().await;
+        }
+        Ok(gateway)
+    }
+
     pub fn new(
         belongs_to: Rc<RefCell<Instance>>,
         token: String,
@@ -109,6 +237,22 @@ impl UserMeta {
             limits,
             settings,
             object,
+            gateway: None,
+            credential_provider: None,
         }
     }
 }
+
+/// Everything needed to restore a logged in [`UserMeta`] without logging in again. Can be
+/// serialized, to keep a session across restarts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSession {
+    pub urls: UrlBundle,
+    pub token: String,
+    pub user_id: Snowflake,
+    pub settings: UserSettings,
+    /// The id of the gateway session, if the user was connected to the gateway.
+    pub gateway_session_id: Option<String>,
+    /// The last sequence number received on the gateway session.
+    pub sequence_number: Option<u64>,
+}
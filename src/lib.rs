@@ -1,7 +1,10 @@
 #![allow(clippy::module_inception)]
 
+use serde::{Deserialize, Serialize};
 use url::{ParseError, Url};
 
+use crate::errors::{ChorusLibError, ChorusResult};
+
 #[cfg(feature = "client")]
 pub mod api;
 pub mod errors;
@@ -11,11 +14,13 @@ pub mod gateway;
 pub mod instance;
 #[cfg(feature = "client")]
 pub mod limit;
+#[cfg(feature = "client")]
+pub mod manager;
 pub mod types;
 #[cfg(feature = "client")]
 pub mod voice;
 
-#[derive(Clone, Default, Debug, PartialEq, Eq)]
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 /// A URLBundle is a struct which bundles together the API-, Gateway- and CDN-URLs of a Spacebar
 /// instance.
 pub struct UrlBundle {
@@ -33,6 +38,16 @@ impl UrlBundle {
         }
     }
 
+    /// Like [`UrlBundle::new`], but returns an error instead of panicking if one of the URLs is
+    /// invalid.
+    pub fn try_new(api: String, wss: String, cdn: String) -> ChorusResult<Self> {
+        Ok(Self {
+            api: UrlBundle::try_parse_url(api)?,
+            wss: UrlBundle::try_parse_url(wss)?,
+            cdn: UrlBundle::try_parse_url(cdn)?,
+        })
+    }
+
     /// parse(url: String) parses a URL using the Url library and formats it in a standardized
     /// way. If no protocol is given, HTTP (not HTTPS) is assumed.
     /// # Example:
@@ -40,26 +55,41 @@ impl UrlBundle {
     /// let url = parse_url("localhost:3000");
     /// ```
     /// `-> Outputs "http://localhost:3000".`
+    /// # Panics
+    /// If the URL is invalid. Use [`UrlBundle::try_parse_url`] to handle invalid URLs.
     pub fn parse_url(url: String) -> String {
+        UrlBundle::try_parse_url(url).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`UrlBundle::parse_url`], but returns an error instead of panicking if the URL is
+    /// invalid.
+    /// # Errors
+    /// * [`ChorusLibError::InvalidUrlError`] - If the URL cannot be parsed.
+    pub fn try_parse_url(url: String) -> ChorusResult<String> {
         let url = match Url::parse(&url) {
             Ok(url) => {
                 if url.scheme() == "localhost" {
-                    return UrlBundle::parse_url(format!("http://{}", url));
+                    return UrlBundle::try_parse_url(format!("http://{}", url));
                 }
                 url
             }
             Err(ParseError::RelativeUrlWithoutBase) => {
                 let url_fmt = format!("http://{}", url);
-                return UrlBundle::parse_url(url_fmt);
+                return UrlBundle::try_parse_url(url_fmt);
+            }
+            Err(e) => {
+                return Err(ChorusLibError::InvalidUrlError {
+                    url,
+                    error: e.to_string(),
+                })
             }
-            Err(_) => panic!("Invalid URL"),
         };
         // if the last character of the string is a slash, remove it.
         let mut url_string = url.to_string();
         if url_string.ends_with('/') {
             url_string.pop();
         }
-        url_string
+        Ok(url_string)
     }
 }
 
@@ -78,4 +108,14 @@ mod lib {
         result = UrlBundle::parse_url(String::from("https://some.url.com"));
         assert_eq!(result, String::from("https://some.url.com"));
     }
+
+    #[test]
+    fn test_try_parse_invalid_url() {
+        assert!(UrlBundle::try_parse_url(String::from("http://[::1")).is_err());
+        assert!(UrlBundle::try_parse_url(String::from("https://exa mple.org")).is_err());
+        assert_eq!(
+            UrlBundle::try_parse_url(String::from("localhost:3000/")).unwrap(),
+            String::from("http://localhost:3000")
+        );
+    }
 }
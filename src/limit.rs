@@ -1,9 +1,11 @@
 use reqwest::{RequestBuilder, Response};
+use serde_json::from_str;
 
 use crate::{
     api::limits::{Limit, LimitType, Limits, LimitsMutRef},
     errors::{ChorusLibError, ChorusResult},
     instance::Instance,
+    types::CaptchaChallenge,
 };
 
 #[derive(Debug)]
@@ -73,6 +75,16 @@ impl LimitedRequester {
                 match response.status().as_u16() {
                     401 => Err(ChorusLibError::TokenExpired),
                     403 => Err(ChorusLibError::TokenExpired),
+                    400 => {
+                        let error_code = response.status().as_str().to_string();
+                        let body = response.text().await.unwrap_or_default();
+                        // Instances with captchas enabled reject requests with a challenge,
+                        // which has to be solved before sending the request again.
+                        match from_str::<CaptchaChallenge>(&body) {
+                            Ok(challenge) => Err(ChorusLibError::CaptchaRequired { challenge }),
+                            Err(_) => Err(ChorusLibError::ReceivedErrorCodeError { error_code }),
+                        }
+                    }
                     _ => Err(ChorusLibError::ReceivedErrorCodeError {
                         error_code: response.status().as_str().to_string(),
                     }),
@@ -243,8 +255,6 @@ impl LimitedRequester {
 
 #[cfg(test)]
 mod rate_limit {
-    use serde_json::from_str;
-
     use crate::{api::limits::Config, UrlBundle};
 
     use super::*;
@@ -0,0 +1,332 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::errors::{ChorusLibError, ChorusResult, GatewayError};
+use crate::gateway::Observer;
+use crate::instance::{Instance, SavedSession, UserMeta};
+use crate::types::{self, LoginSchema, RegisterSchema, Snowflake};
+use crate::UrlBundle;
+
+/// Identifies one account managed by a [`ClientManager`]: the API URL of the instance the account
+/// belongs to, and the id of the user.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccountKey {
+    pub instance: String,
+    pub user_id: Snowflake,
+}
+
+impl fmt::Display for AccountKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}@{}", self.user_id, self.instance)
+    }
+}
+
+/// A gateway event, tagged with the account whose gateway connection received it.
+#[derive(Debug, Clone)]
+pub struct ManagedEvent {
+    pub account: AccountKey,
+    pub dispatch: types::GatewayDispatch,
+}
+
+/// Forwards every event dispatched on one gateway connection into the shared event stream of a
+/// [`ClientManager`].
+#[derive(Debug)]
+struct DispatchForwarder {
+    account: AccountKey,
+    send: UnboundedSender<ManagedEvent>,
+}
+
+impl Observer<types::GatewayDispatch> for DispatchForwarder {
+    fn update(&self, data: &types::GatewayDispatch) {
+        // If the receiving half has been dropped, nobody is interested in the events anymore.
+        let _ = self.send.send(ManagedEvent {
+            account: self.account.clone(),
+            dispatch: data.clone(),
+        });
+    }
+}
+
+/// An account managed by a [`ClientManager`]. The gateway connection of the account is the one of
+/// its [`UserMeta`].
+#[derive(Debug)]
+pub struct ManagedAccount {
+    pub key: AccountKey,
+    pub user: UserMeta,
+    event_send: UnboundedSender<ManagedEvent>,
+    /// Whether the events of the current gateway connection are forwarded to the event stream.
+    forwarding: bool,
+}
+
+impl ManagedAccount {
+    /// Connects to the gateway of the instance this account belongs to and identifies with the
+    /// accounts' token. All events received on this connection are forwarded to the event stream
+    /// of the [`ClientManager`], tagged with this accounts' [`AccountKey`].
+    ///
+    /// An already existing gateway connection of this account will be replaced.
+    pub async fn connect_gateway(&mut self) -> Result<(), GatewayError> {
+        self.user.connect_gateway().await?;
+        self.forward_events().await;
+        Ok(())
+    }
+
+    /// Resumes the gateway session of a [`SavedSession`] instead of starting a new one, see
+    /// [`UserMeta::resume_gateway`].
+    ///
+    /// An already existing gateway connection of this account will be replaced.
+    pub async fn resume_gateway(&mut self, session: &SavedSession) -> Result<(), GatewayError> {
+        self.user.resume_gateway(session).await?;
+        self.forward_events().await;
+        Ok(())
+    }
+
+    /// Returns a [`SavedSession`], from which this account and its gateway session can be
+    /// restored later on using [`ClientManager::restore`].
+    pub async fn save_session(&self) -> SavedSession {
+        self.user.save_session().await
+    }
+
+    /// Closes the gateway connection of this account, if there is one.
+    pub async fn disconnect_gateway(&mut self) {
+        self.user.disconnect_gateway().await;
+        self.forwarding = false;
+    }
+
+    /// Forwards the events of the gateway connection of this account to the event stream, unless
+    /// they are forwarded already. The forwarding is kept when the connection is replaced by
+    /// [`UserMeta::connect_gateway`], e.g. after the token of the account has been renewed.
+    async fn forward_events(&mut self) {
+        if self.forwarding {
+            return;
+        }
+        let Some(gateway) = &self.user.gateway else {
+            return;
+        };
+        let forwarder = Arc::new(DispatchForwarder {
+            account: self.key.clone(),
+            send: self.event_send.clone(),
+        });
+        gateway.events.lock().await.dispatch.subscribe(forwarder);
+        self.forwarding = true;
+    }
+}
+
+/// The [`ClientManager`] holds any number of [`Instance`]s, keyed by their API URL, and any number
+/// of accounts on each of them.
+///
+/// All accounts on the same instance share a single [`Instance`], so instance-wide rate limits are
+/// tracked correctly across accounts. Events received on the gateway connections of all accounts
+/// are merged into one stream of [`ManagedEvent`]s, see [`ClientManager::take_events`].
+#[derive(Debug)]
+pub struct ClientManager {
+    instances: HashMap<String, Rc<RefCell<Instance>>>,
+    accounts: HashMap<AccountKey, ManagedAccount>,
+    event_send: UnboundedSender<ManagedEvent>,
+    event_receive: Option<UnboundedReceiver<ManagedEvent>>,
+}
+
+impl Default for ClientManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClientManager {
+    /// Creates a new [`ClientManager`] without any instances or accounts.
+    pub fn new() -> ClientManager {
+        let (event_send, event_receive) = unbounded_channel();
+        ClientManager {
+            instances: HashMap::new(),
+            accounts: HashMap::new(),
+            event_send,
+            event_receive: Some(event_receive),
+        }
+    }
+
+    /// Connects to the instance at the given [`UrlBundle`], if it is not managed already.
+    ///
+    /// # Returns
+    /// The shared [`Instance`], which all accounts on it will use.
+    pub async fn add_instance(&mut self, urls: UrlBundle) -> ChorusResult<Rc<RefCell<Instance>>> {
+        if let Some(instance) = self.instances.get(&urls.api) {
+            return Ok(instance.clone());
+        }
+        let api_url = urls.api.clone();
+        let instance = Rc::new(RefCell::new(Instance::new(urls).await?));
+        self.instances.insert(api_url, instance.clone());
+        Ok(instance)
+    }
+
+    /// Returns the managed [`Instance`] with the given API URL, if there is one. Returns `None` if
+    /// the URL is invalid.
+    pub fn instance(&self, api_url: &str) -> Option<Rc<RefCell<Instance>>> {
+        let api_url = UrlBundle::try_parse_url(api_url.to_string()).ok()?;
+        self.instances.get(&api_url).cloned()
+    }
+
+    /// Returns the API URLs of all managed instances.
+    pub fn instances(&self) -> impl Iterator<Item = &String> {
+        self.instances.keys()
+    }
+
+    /// Logs in to an account on an already managed instance and starts managing it.
+    ///
+    /// # Errors
+    /// * [`ChorusLibError::InvalidUrlError`] - If the API URL is invalid.
+    /// * [`ChorusLibError::NotFound`] - If no instance with the given API URL is managed.
+    /// * [`ChorusLibError`] - If the login fails.
+    pub async fn login(
+        &mut self,
+        api_url: &str,
+        login_schema: &LoginSchema,
+    ) -> ChorusResult<AccountKey> {
+        let instance = self.get_instance(api_url)?;
+        // Work on a copy, so the shared instance isn't borrowed across the request. The rate
+        // limits which have been used up by the request are copied back afterwards.
+        let mut working_copy = instance.borrow().clone();
+        let user = working_copy.login_account(login_schema).await;
+        instance.borrow_mut().limits = working_copy.limits;
+        Ok(self.add_account(user?))
+    }
+
+    /// Registers a new account on an already managed instance and starts managing it.
+    ///
+    /// # Errors
+    /// * [`ChorusLibError::InvalidUrlError`] - If the API URL is invalid.
+    /// * [`ChorusLibError::NotFound`] - If no instance with the given API URL is managed.
+    /// * [`ChorusLibError`] - If the registration fails.
+    pub async fn register(
+        &mut self,
+        api_url: &str,
+        register_schema: &RegisterSchema,
+    ) -> ChorusResult<AccountKey> {
+        let instance = self.get_instance(api_url)?;
+        let mut working_copy = instance.borrow().clone();
+        let user = working_copy.register_account(register_schema).await;
+        instance.borrow_mut().limits = working_copy.limits;
+        Ok(self.add_account(user?))
+    }
+
+    /// Starts managing an account, which has been logged in to elsewhere.
+    ///
+    /// If the instance of the account is managed already, the account will be moved over to the
+    /// managed [`Instance`]. Otherwise, the accounts' instance will be managed from now on.
+    pub fn add_account(&mut self, mut user: UserMeta) -> AccountKey {
+        let api_url = user.belongs_to.borrow().urls.api.clone();
+        let instance = self
+            .instances
+            .entry(api_url.clone())
+            .or_insert_with(|| user.belongs_to.clone())
+            .clone();
+        user.belongs_to = instance;
+        let key = AccountKey {
+            instance: api_url,
+            user_id: user.object.id,
+        };
+        let account = ManagedAccount {
+            key: key.clone(),
+            user,
+            event_send: self.event_send.clone(),
+            forwarding: false,
+        };
+        self.accounts.insert(key.clone(), account);
+        key
+    }
+
+    /// Restores an account from a [`SavedSession`] without logging in again, starts managing it
+    /// and resumes its gateway session.
+    ///
+    /// The instance of the session will be connected to, if it is not managed already.
+    ///
+    /// # Errors
+    /// * [`ChorusLibError::TokenExpired`] - If the token of the session is no longer valid.
+    /// * [`ChorusLibError::GatewayConnectionError`] - If the gateway session could not be resumed.
+    ///   The account is managed nonetheless.
+    pub async fn restore(&mut self, session: &SavedSession) -> ChorusResult<AccountKey> {
+        let instance = self.add_instance(session.urls.clone()).await?;
+        let mut working_copy = instance.borrow().clone();
+        let user = working_copy.restore_account(session).await;
+        instance.borrow_mut().limits = working_copy.limits;
+        let key = self.add_account(user?);
+        self.accounts
+            .get_mut(&key)
+            .unwrap()
+            .resume_gateway(session)
+            .await
+            .map_err(|e| ChorusLibError::GatewayConnectionError {
+                error: e.to_string(),
+            })?;
+        Ok(key)
+    }
+
+    /// Stops managing an account and returns it. Its gateway connection is kept open.
+    pub fn remove_account(&mut self, key: &AccountKey) -> Option<ManagedAccount> {
+        self.accounts.remove(key)
+    }
+
+    pub fn account(&self, key: &AccountKey) -> Option<&ManagedAccount> {
+        self.accounts.get(key)
+    }
+
+    pub fn account_mut(&mut self, key: &AccountKey) -> Option<&mut ManagedAccount> {
+        self.accounts.get_mut(key)
+    }
+
+    /// Returns all managed accounts.
+    pub fn accounts(&self) -> impl Iterator<Item = &ManagedAccount> {
+        self.accounts.values()
+    }
+
+    /// Returns all managed accounts on the instance with the given API URL. Returns no accounts if
+    /// the URL is invalid.
+    pub fn accounts_on<'a>(
+        &'a self,
+        api_url: &'a str,
+    ) -> impl Iterator<Item = &'a ManagedAccount> + 'a {
+        let api_url = UrlBundle::try_parse_url(api_url.to_string()).ok();
+        self.accounts
+            .values()
+            .filter(move |account| api_url.as_ref() == Some(&account.key.instance))
+    }
+
+    /// Connects every managed account without a gateway connection to its instances' gateway.
+    ///
+    /// # Returns
+    /// The accounts which could not be connected, together with the reason why.
+    pub async fn connect_all(&mut self) -> Vec<(AccountKey, GatewayError)> {
+        let mut failed = Vec::new();
+        for account in self.accounts.values_mut() {
+            if account.user.gateway.is_some() {
+                // Make sure the events of connections opened elsewhere are forwarded as well.
+                account.forward_events().await;
+                continue;
+            }
+            if let Err(e) = account.connect_gateway().await {
+                failed.push((account.key.clone(), e));
+            }
+        }
+        failed
+    }
+
+    /// Takes the receiving end of the event stream, on which the events of all gateway
+    /// connections of all managed accounts arrive, tagged with the account which received them.
+    ///
+    /// Returns [`None`] if the receiver has already been taken.
+    pub fn take_events(&mut self) -> Option<UnboundedReceiver<ManagedEvent>> {
+        self.event_receive.take()
+    }
+
+    fn get_instance(&self, api_url: &str) -> ChorusResult<Rc<RefCell<Instance>>> {
+        let parsed_url = UrlBundle::try_parse_url(api_url.to_string())?;
+        self.instances
+            .get(&parsed_url)
+            .cloned()
+            .ok_or_else(|| ChorusLibError::NotFound {
+                error: format!("No instance with the API URL {} is managed.", api_url),
+            })
+    }
+}
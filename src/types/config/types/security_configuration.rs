@@ -1,9 +1,12 @@
 use base64::Engine;
+use jsonwebtoken::Algorithm;
+use openssl::error::ErrorStack;
 use serde::{Deserialize, Serialize};
 
 use crate::types::config::types::subconfigs::security::{
     CaptchaConfiguration, TwoFactorConfiguration,
 };
+use crate::types::{BackupCode, Snowflake};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -13,6 +16,10 @@ pub struct SecurityConfiguration {
     pub auto_update: bool,
     pub request_signature: String,
     pub jwt_secret: String,
+    /// The algorithm tokens are signed with using the `jwt_secret`. Only the HMAC algorithms are
+    /// supported.
+    #[serde(default = "default_jwt_algorithm")]
+    pub jwt_algorithm: Algorithm,
     pub forwarded_for: Option<String>,
     pub ipdata_api_key: Option<String>,
     pub mfa_backup_code_count: u8,
@@ -20,6 +27,10 @@ pub struct SecurityConfiguration {
     pub default_registration_token_expiration: u64,
 }
 
+fn default_jwt_algorithm() -> Algorithm {
+    Algorithm::HS256
+}
+
 impl Default for SecurityConfiguration {
     fn default() -> Self {
         let mut req_sig: [u8; 32] = [0; 32];
@@ -32,6 +43,7 @@ impl Default for SecurityConfiguration {
             auto_update: true,
             request_signature: base64::engine::general_purpose::STANDARD.encode(req_sig),
             jwt_secret: base64::engine::general_purpose::STANDARD.encode(jwt_secret),
+            jwt_algorithm: default_jwt_algorithm(),
             forwarded_for: None,
             ipdata_api_key: Some(String::from(
                 "eca677b284b3bac29eb72f5e496aa9047f26543605efe99ff2ce35c9",
@@ -42,3 +54,45 @@ impl Default for SecurityConfiguration {
         }
     }
 }
+
+impl SecurityConfiguration {
+    /// Generates a fresh set of [`BackupCode`]s for the user with the given id.
+    ///
+    /// Generates `mfa_backup_code_count` codes, or none at all if the generation of backup codes
+    /// is disabled in the [`TwoFactorConfiguration`].
+    ///
+    /// # Errors
+    /// If the random number generator of OpenSSL fails.
+    pub fn generate_backup_codes(&self, user_id: Snowflake) -> Result<Vec<BackupCode>, ErrorStack> {
+        if !self.two_factor.generate_backup_codes {
+            return Ok(Vec::new());
+        }
+        (0..self.mfa_backup_code_count)
+            .map(|_| BackupCode::generate(user_id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod backup_codes {
+    use super::*;
+
+    #[test]
+    fn honours_two_factor_configuration() {
+        let mut config = SecurityConfiguration {
+            mfa_backup_code_count: 4,
+            ..Default::default()
+        };
+        let codes = config.generate_backup_codes(Snowflake::generate()).unwrap();
+        assert_eq!(codes.len(), 4);
+        assert!(codes
+            .iter()
+            .all(|code| code.code.len() == 8 && !code.consumed));
+
+        config.two_factor.generate_backup_codes = false;
+        assert!(config
+            .generate_backup_codes(Snowflake::generate())
+            .unwrap()
+            .is_empty());
+    }
+}
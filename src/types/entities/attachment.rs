@@ -1,6 +1,14 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use futures_util::Stream;
+use reqwest::multipart::Part;
+use reqwest::Body;
 use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt};
 
-use crate::types::utils::Snowflake;
+use crate::types::utils::{check_size, Snowflake, Validate};
+use crate::types::{LimitsConfiguration, ValidationError};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
@@ -111,3 +119,286 @@ impl PartialDiscordFileAttachment {
         self.id = Some(id);
     }
 }
+
+impl Validate for Vec<PartialDiscordFileAttachment> {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        for attachment in self {
+            check_size(
+                "attachment",
+                attachment.content.len() as u64,
+                limits.message.max_attachment_size,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A file to upload as an attachment of a message. The content is only read when the message is
+/// sent, so files do not have to be loaded into memory as a whole.
+pub struct FileUpload {
+    /// The name of the file, without the `SPOILER_` prefix of spoilers.
+    pub filename: String,
+    /// The alt text of the file.
+    pub description: Option<String>,
+    /// The MIME type of the file, guessed from the extension of `filename` unless set.
+    pub content_type: Option<String>,
+    /// Whether the file is hidden until clicked.
+    pub spoiler: bool,
+    size: u64,
+    source: UploadSource,
+}
+
+enum UploadSource {
+    Bytes(Vec<u8>),
+    Path(PathBuf),
+    Reader(Box<dyn AsyncRead + Send + Sync + Unpin>),
+}
+
+impl FileUpload {
+    pub fn from_bytes(filename: &str, content: Vec<u8>) -> Self {
+        Self::new(filename, content.len() as u64, UploadSource::Bytes(content))
+    }
+
+    /// Uploads the file at `path`, which is opened when the message is sent.
+    ///
+    /// # Errors
+    /// If the metadata of the file cannot be read, for example because it does not exist.
+    pub async fn from_path(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let size = tokio::fs::metadata(path).await?.len();
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(Self::new(
+            &filename,
+            size,
+            UploadSource::Path(path.to_path_buf()),
+        ))
+    }
+
+    /// Uploads the `size` bytes read from `reader`. The size has to be known in advance, so that
+    /// it can be checked against the limits of the instance. Bytes after the first `size` are not
+    /// uploaded, and sending the message fails if the reader ends early.
+    pub fn from_reader(
+        filename: &str,
+        reader: impl AsyncRead + Send + Sync + Unpin + 'static,
+        size: u64,
+    ) -> Self {
+        Self::new(filename, size, UploadSource::Reader(Box::new(reader)))
+    }
+
+    fn new(filename: &str, size: u64, source: UploadSource) -> Self {
+        Self {
+            filename: filename.to_string(),
+            description: None,
+            content_type: None,
+            spoiler: false,
+            size,
+            source,
+        }
+    }
+
+    pub fn description(self, description: &str) -> Self {
+        Self {
+            description: Some(description.to_string()),
+            ..self
+        }
+    }
+
+    pub fn content_type(self, content_type: &str) -> Self {
+        Self {
+            content_type: Some(content_type.to_string()),
+            ..self
+        }
+    }
+
+    pub fn spoiler(self, spoiler: bool) -> Self {
+        Self { spoiler, ..self }
+    }
+
+    /// The size of the file in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The name the file is uploaded with, prefixed with `SPOILER_` for spoilers.
+    pub fn upload_filename(&self) -> String {
+        if self.spoiler && !self.filename.starts_with("SPOILER_") {
+            format!("SPOILER_{}", self.filename)
+        } else {
+            self.filename.clone()
+        }
+    }
+
+    /// The MIME type of the file, either as set or guessed from the extension of its name.
+    pub fn mime_type(&self) -> String {
+        self.content_type.clone().unwrap_or_else(|| {
+            mime_guess::from_path(&self.filename)
+                .first_or_octet_stream()
+                .to_string()
+        })
+    }
+
+    /// The metadata of the file, as listed in the `attachments` of the message. `id` is the
+    /// index of the file among the uploaded files.
+    pub fn attachment(&self, id: i16) -> PartialDiscordFileAttachment {
+        PartialDiscordFileAttachment {
+            id: Some(id),
+            filename: self.upload_filename(),
+            description: self.description.clone(),
+            content_type: Some(self.mime_type()),
+            size: Some(self.size as i64),
+            url: None,
+            proxy_url: None,
+            height: None,
+            width: None,
+            ephemeral: None,
+            duration_secs: None,
+            waveform: None,
+            content: Vec::new(),
+        }
+    }
+
+    /// Turns the file into a part of a multipart form, opening it if it is read from a path.
+    pub(crate) async fn into_part(self) -> std::io::Result<Part> {
+        let filename = self.upload_filename();
+        let mime_type = self.mime_type();
+        let part = match self.source {
+            UploadSource::Bytes(content) => Part::bytes(content),
+            UploadSource::Path(path) => {
+                let file = tokio::fs::File::open(path).await?;
+                let stream = exact_length_stream(file, self.size);
+                Part::stream_with_length(Body::wrap_stream(stream), self.size)
+            }
+            UploadSource::Reader(reader) => {
+                let stream = exact_length_stream(reader, self.size);
+                Part::stream_with_length(Body::wrap_stream(stream), self.size)
+            }
+        };
+        part.file_name(filename)
+            .mime_str(&mime_type)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidInput, error))
+    }
+}
+
+/// Streams exactly `size` bytes of `reader`, as announced in the multipart form. Fails if the reader
+/// ends early, instead of sending a broken form.
+fn exact_length_stream(
+    reader: impl AsyncRead + Send + Sync + Unpin + 'static,
+    size: u64,
+) -> impl Stream<Item = std::io::Result<Vec<u8>>> + Send + Sync + 'static {
+    const CHUNK_SIZE: u64 = 64 * 1024;
+    futures_util::stream::unfold(
+        (reader.take(size), size),
+        |(mut reader, remaining)| async move {
+            if remaining == 0 {
+                return None;
+            }
+            let mut chunk = vec![0; remaining.min(CHUNK_SIZE) as usize];
+            match reader.read(&mut chunk).await {
+                Ok(0) => {
+                    let error = std::io::Error::new(
+                        std::io::ErrorKind::UnexpectedEof,
+                        format!(
+                            "the file ended {} bytes before its declared size",
+                            remaining
+                        ),
+                    );
+                    Some((Err(error), (reader, 0)))
+                }
+                Ok(read) => {
+                    chunk.truncate(read);
+                    Some((Ok(chunk), (reader, remaining - read as u64)))
+                }
+                Err(error) => Some((Err(error), (reader, 0))),
+            }
+        },
+    )
+}
+
+impl fmt::Debug for FileUpload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = match &self.source {
+            UploadSource::Bytes(_) => "bytes".to_string(),
+            UploadSource::Path(path) => path.display().to_string(),
+            UploadSource::Reader(_) => "reader".to_string(),
+        };
+        f.debug_struct("FileUpload")
+            .field("filename", &self.filename)
+            .field("description", &self.description)
+            .field("content_type", &self.content_type)
+            .field("spoiler", &self.spoiler)
+            .field("size", &self.size)
+            .field("source", &source)
+            .finish()
+    }
+}
+
+impl From<PartialDiscordFileAttachment> for FileUpload {
+    fn from(attachment: PartialDiscordFileAttachment) -> Self {
+        let upload = FileUpload::from_bytes(&attachment.filename, attachment.content);
+        FileUpload {
+            description: attachment.description,
+            content_type: attachment.content_type,
+            ..upload
+        }
+    }
+}
+
+impl Validate for Vec<FileUpload> {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        for upload in self {
+            check_size(
+                "attachment",
+                upload.size,
+                limits.message.max_attachment_size,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod attachment {
+    use super::*;
+
+    #[test]
+    fn file_upload_metadata() {
+        let upload = FileUpload::from_bytes("cat.png", vec![0; 16])
+            .spoiler(true)
+            .description("A cat");
+        let attachment = upload.attachment(3);
+        assert_eq!(attachment.id, Some(3));
+        assert_eq!(attachment.filename, "SPOILER_cat.png");
+        assert_eq!(attachment.content_type.as_deref(), Some("image/png"));
+        assert_eq!(attachment.size, Some(16));
+        assert_eq!(attachment.description.as_deref(), Some("A cat"));
+        assert_eq!(
+            FileUpload::from_bytes("data", Vec::new()).mime_type(),
+            "application/octet-stream"
+        );
+
+        let mut limits = LimitsConfiguration::default();
+        limits.message.max_attachment_size = 15;
+        assert!(vec![upload].validate(&limits).is_err());
+    }
+
+    #[tokio::test]
+    async fn reader_length() {
+        use futures_util::TryStreamExt;
+
+        let chunks: Vec<Vec<u8>> = exact_length_stream(&[1, 2, 3, 4][..], 3)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(chunks.concat(), vec![1, 2, 3]);
+
+        let result: std::io::Result<Vec<Vec<u8>>> =
+            exact_length_stream(&[1, 2][..], 3).try_collect().await;
+        assert_eq!(
+            result.unwrap_err().kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use openssl::error::ErrorStack;
+use serde::{Deserialize, Serialize};
+
+use crate::types::utils::Snowflake;
+
+/// A single use code, which can be used instead of a TOTP code to log in to an account with
+/// two-factor authentication enabled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+pub struct BackupCode {
+    #[serde(default)]
+    pub user_id: Option<Snowflake>,
+    pub code: String,
+    pub consumed: bool,
+    #[serde(default)]
+    pub expired: bool,
+}
+
+impl BackupCode {
+    /// Generates a new, unused backup code for the user with the given id. The code consists of
+    /// 8 random hexadecimal characters.
+    ///
+    /// # Errors
+    /// If the random number generator of OpenSSL fails.
+    pub fn generate(user_id: Snowflake) -> Result<Self, ErrorStack> {
+        let mut bytes: [u8; 4] = [0; 4];
+        openssl::rand::rand_bytes(&mut bytes)?;
+        Ok(Self {
+            user_id: Some(user_id),
+            code: bytes.iter().map(|byte| format!("{:02x}", byte)).collect(),
+            consumed: false,
+            expired: false,
+        })
+    }
+}
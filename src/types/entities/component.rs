@@ -0,0 +1,476 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::types::utils::{check_length, Validate};
+use crate::types::{ChannelType, LimitsConfiguration, Snowflake, ValidationError};
+
+/// The maximum number of action rows of a message or modal.
+pub const MAX_ACTION_ROWS: usize = 5;
+/// The maximum width of an action row. Buttons have a width of 1, select menus and text inputs
+/// take up the whole row.
+pub const MAX_ACTION_ROW_WIDTH: usize = 5;
+pub const MAX_CUSTOM_ID_LENGTH: u64 = 100;
+pub const MAX_BUTTON_LABEL_LENGTH: u64 = 80;
+pub const MAX_SELECT_OPTIONS: usize = 25;
+pub const MAX_TEXT_INPUT_LENGTH: u64 = 4000;
+
+/// An interactive component of a message or modal.
+///
+/// See <https://discord.com/developers/docs/interactions/message-components>
+#[derive(Debug, Clone, PartialEq)]
+pub enum Component {
+    ActionRow(ActionRow),
+    Button(Button),
+    StringSelect(SelectMenu),
+    TextInput(TextInput),
+    UserSelect(SelectMenu),
+    RoleSelect(SelectMenu),
+    MentionableSelect(SelectMenu),
+    ChannelSelect(SelectMenu),
+    /// A component of a type this library does not know about, kept as it was received.
+    Unknown(Value),
+}
+
+impl Component {
+    /// The numeric type of the component, as used in its JSON representation.
+    pub fn component_type(&self) -> Option<u64> {
+        Some(match self {
+            Component::ActionRow(_) => 1,
+            Component::Button(_) => 2,
+            Component::StringSelect(_) => 3,
+            Component::TextInput(_) => 4,
+            Component::UserSelect(_) => 5,
+            Component::RoleSelect(_) => 6,
+            Component::MentionableSelect(_) => 7,
+            Component::ChannelSelect(_) => 8,
+            Component::Unknown(value) => return value.get("type")?.as_u64(),
+        })
+    }
+
+    /// How much of an action row the component takes up, see [`MAX_ACTION_ROW_WIDTH`].
+    fn width(&self) -> usize {
+        match self {
+            Component::Button(_) => 1,
+            _ => MAX_ACTION_ROW_WIDTH,
+        }
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            Component::ActionRow(row) => serde_json::to_value(row),
+            Component::Button(button) => serde_json::to_value(button),
+            Component::TextInput(input) => serde_json::to_value(input),
+            Component::StringSelect(menu)
+            | Component::UserSelect(menu)
+            | Component::RoleSelect(menu)
+            | Component::MentionableSelect(menu)
+            | Component::ChannelSelect(menu) => serde_json::to_value(menu),
+            Component::Unknown(value) => return value.serialize(serializer),
+        };
+        let mut value = value.map_err(serde::ser::Error::custom)?;
+        if let (Value::Object(object), Some(component_type)) = (&mut value, self.component_type()) {
+            object.insert("type".to_string(), component_type.into());
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let component_type = value.get("type").and_then(Value::as_u64);
+        let component = match component_type {
+            Some(1) => serde_json::from_value(value).map(Component::ActionRow),
+            Some(2) => serde_json::from_value(value).map(Component::Button),
+            Some(3) => serde_json::from_value(value).map(Component::StringSelect),
+            Some(4) => serde_json::from_value(value).map(Component::TextInput),
+            Some(5) => serde_json::from_value(value).map(Component::UserSelect),
+            Some(6) => serde_json::from_value(value).map(Component::RoleSelect),
+            Some(7) => serde_json::from_value(value).map(Component::MentionableSelect),
+            Some(8) => serde_json::from_value(value).map(Component::ChannelSelect),
+            _ => Ok(Component::Unknown(value)),
+        };
+        component.map_err(serde::de::Error::custom)
+    }
+}
+
+/// A row of components. Messages and modals can have up to [`MAX_ACTION_ROWS`] rows.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionRow {
+    pub components: Vec<Component>,
+}
+
+impl ActionRow {
+    pub fn new(components: Vec<Component>) -> Self {
+        Self { components }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum ButtonStyle {
+    #[default]
+    Primary = 1,
+    Secondary = 2,
+    Success = 3,
+    Danger = 4,
+    /// Opens the `url` of the button instead of sending an interaction.
+    Link = 5,
+}
+
+/// The emoji shown on a button or select option. Unicode emoji only have a `name`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentEmoji {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animated: Option<bool>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Button {
+    pub style: ButtonStyle,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ComponentEmoji>,
+    /// Sent with the interaction when the button is clicked. Required, unless the button is a
+    /// link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+    /// Required for, and only allowed for, link buttons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+impl Button {
+    pub fn new(style: ButtonStyle, label: &str, custom_id: &str) -> Self {
+        Self {
+            style,
+            label: Some(label.to_string()),
+            custom_id: Some(custom_id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn link(label: &str, url: &str) -> Self {
+        Self {
+            style: ButtonStyle::Link,
+            label: Some(label.to_string()),
+            url: Some(url.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+/// A select menu. Which values can be selected depends on the kind of the [`Component`]: the
+/// given `options` for [`Component::StringSelect`], otherwise users, roles, both or channels.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelectMenu {
+    pub custom_id: String,
+    /// The options of a [`Component::StringSelect`], at most [`MAX_SELECT_OPTIONS`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<SelectOption>>,
+    /// The channel types which can be selected in a [`Component::ChannelSelect`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_types: Option<Vec<ChannelType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_values: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_values: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelectOption {
+    pub label: String,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ComponentEmoji>,
+    /// Whether the option is selected by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+}
+
+impl SelectOption {
+    pub fn new(label: &str, value: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            value: value.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum TextInputStyle {
+    /// A single line.
+    #[default]
+    Short = 1,
+    /// Multiple lines.
+    Paragraph = 2,
+}
+
+/// A text input, only allowed in modals.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextInput {
+    pub custom_id: String,
+    pub style: TextInputStyle,
+    pub label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    /// The text the input is prefilled with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+}
+
+fn invalid(field: &'static str, reason: String) -> ValidationError {
+    ValidationError::Invalid { field, reason }
+}
+
+fn check_values(min: Option<u8>, max: Option<u8>) -> Result<(), ValidationError> {
+    let max_allowed = MAX_SELECT_OPTIONS as u8;
+    if min.is_some_and(|min| min > max_allowed) {
+        return Err(invalid(
+            "min_values",
+            format!("must be at most {}", max_allowed),
+        ));
+    }
+    if max.is_some_and(|max| max == 0 || max > max_allowed) {
+        return Err(invalid(
+            "max_values",
+            format!("must be between 1 and {}", max_allowed),
+        ));
+    }
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            return Err(invalid(
+                "min_values",
+                "must not be larger than max_values".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+impl Validate for Button {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(label) = &self.label {
+            check_length("label", label, 0, MAX_BUTTON_LABEL_LENGTH)?;
+        }
+        if self.label.is_none() && self.emoji.is_none() {
+            return Err(invalid(
+                "label",
+                "a button needs a label or an emoji".to_string(),
+            ));
+        }
+        match (self.style, &self.custom_id, &self.url) {
+            (ButtonStyle::Link, None, Some(_)) => Ok(()),
+            (ButtonStyle::Link, _, _) => Err(invalid(
+                "url",
+                "link buttons need a url and no custom_id".to_string(),
+            )),
+            (_, Some(custom_id), None) => {
+                check_length("custom_id", custom_id, 1, MAX_CUSTOM_ID_LENGTH)
+            }
+            (_, _, _) => Err(invalid(
+                "custom_id",
+                "buttons which are not links need a custom_id and no url".to_string(),
+            )),
+        }
+    }
+}
+
+impl Validate for SelectMenu {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_length("custom_id", &self.custom_id, 1, MAX_CUSTOM_ID_LENGTH)?;
+        if let Some(placeholder) = &self.placeholder {
+            check_length("placeholder", placeholder, 0, 150)?;
+        }
+        check_values(self.min_values, self.max_values)?;
+        for option in self.options.iter().flatten() {
+            check_length("label", &option.label, 1, 100)?;
+            check_length("value", &option.value, 1, 100)?;
+            if let Some(description) = &option.description {
+                check_length("description", description, 0, 100)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Validate for TextInput {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_length("custom_id", &self.custom_id, 1, MAX_CUSTOM_ID_LENGTH)?;
+        check_length("label", &self.label, 1, 45)?;
+        if self
+            .min_length
+            .is_some_and(|min| min as u64 > MAX_TEXT_INPUT_LENGTH)
+        {
+            return Err(invalid(
+                "min_length",
+                format!("must be at most {}", MAX_TEXT_INPUT_LENGTH),
+            ));
+        }
+        if self
+            .max_length
+            .is_some_and(|max| max == 0 || max as u64 > MAX_TEXT_INPUT_LENGTH)
+        {
+            return Err(invalid(
+                "max_length",
+                format!("must be between 1 and {}", MAX_TEXT_INPUT_LENGTH),
+            ));
+        }
+        if let Some(value) = &self.value {
+            check_length("value", value, 0, MAX_TEXT_INPUT_LENGTH)?;
+        }
+        if let Some(placeholder) = &self.placeholder {
+            check_length("placeholder", placeholder, 0, 100)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for ActionRow {
+    /// Action rows hold at most [`MAX_ACTION_ROW_WIDTH`] buttons, or a single select menu or
+    /// text input, and cannot be nested.
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if self.components.is_empty() {
+            return Err(ValidationError::Missing {
+                field: "components",
+            });
+        }
+        let width: usize = self.components.iter().map(Component::width).sum();
+        if width > MAX_ACTION_ROW_WIDTH {
+            return Err(invalid(
+                "components",
+                format!(
+                    "an action row can hold at most {} buttons, or a single select menu or text input",
+                    MAX_ACTION_ROW_WIDTH
+                ),
+            ));
+        }
+        for component in &self.components {
+            match component {
+                Component::ActionRow(_) => {
+                    return Err(invalid(
+                        "components",
+                        "action rows cannot be nested".to_string(),
+                    ))
+                }
+                Component::Button(button) => button.validate(limits)?,
+                Component::TextInput(input) => input.validate(limits)?,
+                Component::StringSelect(menu) => {
+                    let options = menu.options.as_ref().map_or(0, Vec::len);
+                    if options == 0 || options > MAX_SELECT_OPTIONS {
+                        return Err(invalid(
+                            "options",
+                            format!("must contain between 1 and {} options", MAX_SELECT_OPTIONS),
+                        ));
+                    }
+                    menu.validate(limits)?
+                }
+                Component::UserSelect(menu)
+                | Component::RoleSelect(menu)
+                | Component::MentionableSelect(menu)
+                | Component::ChannelSelect(menu) => menu.validate(limits)?,
+                Component::Unknown(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Validate for Vec<Component> {
+    /// Messages and modals consist of at most [`MAX_ACTION_ROWS`] action rows.
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if self.len() > MAX_ACTION_ROWS {
+            return Err(invalid(
+                "components",
+                format!(
+                    "at most {} action rows are allowed, but got {}",
+                    MAX_ACTION_ROWS,
+                    self.len()
+                ),
+            ));
+        }
+        for component in self {
+            match component {
+                Component::ActionRow(row) => row.validate(limits)?,
+                Component::Unknown(_) => {}
+                _ => {
+                    return Err(invalid(
+                        "components",
+                        "components have to be placed in action rows".to_string(),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod component {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let json = r#"[{"type":1,"components":[{"type":2,"style":1,"label":"Click","custom_id":"click"},{"type":2,"style":5,"label":"Docs","url":"https://example.org"}]},{"type":1,"components":[{"type":3,"custom_id":"pick","options":[{"label":"A","value":"a"}],"max_values":1}]},{"type":42,"custom":true}]"#;
+        let components: Vec<Component> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            components[0],
+            Component::ActionRow(ActionRow::new(vec![
+                Component::Button(Button::new(ButtonStyle::Primary, "Click", "click")),
+                Component::Button(Button::link("Docs", "https://example.org")),
+            ]))
+        );
+        assert!(matches!(components[2], Component::Unknown(_)));
+        let serialized: Value = serde_json::to_value(&components).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<Value>(json).unwrap());
+    }
+
+    #[test]
+    fn validation() {
+        let limits = LimitsConfiguration::default();
+        let button = || Component::Button(Button::new(ButtonStyle::Success, "Ok", "ok"));
+        let select = Component::StringSelect(SelectMenu {
+            custom_id: "pick".to_string(),
+            options: Some(vec![SelectOption::new("A", "a")]),
+            ..Default::default()
+        });
+        let row = |components| Component::ActionRow(ActionRow::new(components));
+
+        assert!(vec![row(vec![button(); 5]), row(vec![select.clone()])]
+            .validate(&limits)
+            .is_ok());
+        assert!(vec![row(vec![button(); 6])].validate(&limits).is_err());
+        assert!(vec![row(vec![button(), select])].validate(&limits).is_err());
+        assert!(vec![button()].validate(&limits).is_err());
+        assert!(vec![row(vec![button()]); 6].validate(&limits).is_err());
+
+        let mut link = Button::link("Docs", "https://example.org");
+        assert!(link.validate(&limits).is_ok());
+        link.custom_id = Some("docs".to_string());
+        assert!(link.validate(&limits).is_err());
+    }
+}
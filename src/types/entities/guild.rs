@@ -4,7 +4,9 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::types::types::guild_configuration::GuildFeaturesList;
 use crate::types::{
-    entities::{Channel, Emoji, RoleObject, Sticker, User, VoiceState, Webhook},
+    entities::{
+        Channel, Emoji, GuildMember, PublicUser, RoleObject, Sticker, User, VoiceState, Webhook,
+    },
     interfaces::WelcomeScreenObject,
     utils::Snowflake,
 };
@@ -120,6 +122,11 @@ pub struct GuildInvite {
     pub target_user: Option<String>,
     pub target_user_type: Option<i32>,
     pub vanity_url: Option<bool>,
+    /// Only set when resolving an invite with counts, see [`GuildInvite::get`].
+    #[cfg_attr(feature = "sqlx", sqlx(skip))]
+    pub approximate_member_count: Option<i32>,
+    #[cfg_attr(feature = "sqlx", sqlx(skip))]
+    pub approximate_presence_count: Option<i32>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
@@ -141,7 +148,7 @@ pub struct GuildScheduledEvent {
     pub channel_id: Option<Snowflake>,
     pub creator_id: Option<Snowflake>,
     pub name: String,
-    pub description: String,
+    pub description: Option<String>,
     pub scheduled_start_time: DateTime<Utc>,
     pub scheduled_end_time: Option<DateTime<Utc>>,
     pub privacy_level: GuildScheduledEventPrivacyLevel,
@@ -154,7 +161,7 @@ pub struct GuildScheduledEvent {
     pub image: Option<String>,
 }
 
-#[derive(Serialize_repr, Deserialize_repr, Debug, Default, Clone)]
+#[derive(Serialize_repr, Deserialize_repr, Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 /// See https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-privacy-level
 pub enum GuildScheduledEventPrivacyLevel {
@@ -162,7 +169,7 @@ pub enum GuildScheduledEventPrivacyLevel {
     GuildOnly = 2,
 }
 
-#[derive(Serialize_repr, Deserialize_repr, Debug, Default, Clone)]
+#[derive(Serialize_repr, Deserialize_repr, Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 /// See https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-status
 pub enum GuildScheduledEventStatus {
@@ -173,7 +180,20 @@ pub enum GuildScheduledEventStatus {
     Canceled = 4,
 }
 
-#[derive(Serialize_repr, Deserialize_repr, Debug, Default, Clone)]
+impl GuildScheduledEventStatus {
+    /// Whether an event with this status can be changed to `status`. Scheduled events can be
+    /// started or canceled, active events can only be completed.
+    pub fn can_transition_to(self, status: GuildScheduledEventStatus) -> bool {
+        matches!(
+            (self, status),
+            (Self::Scheduled, Self::Active)
+                | (Self::Scheduled, Self::Canceled)
+                | (Self::Active, Self::Completed)
+        )
+    }
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 /// See https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-types
 pub enum GuildScheduledEventEntityType {
@@ -183,8 +203,18 @@ pub enum GuildScheduledEventEntityType {
     External = 3,
 }
 
-#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
 /// See https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-metadata
 pub struct GuildScheduledEventEntityMetadata {
     pub location: Option<String>,
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// A user subscribed to a scheduled event.
+/// See https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-user-object
+pub struct GuildScheduledEventUser {
+    pub guild_scheduled_event_id: Snowflake,
+    pub user: PublicUser,
+    /// Only set if requested with [`crate::types::GetScheduledEventUsersSchema::with_member`].
+    pub member: Option<GuildMember>,
+}
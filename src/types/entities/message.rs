@@ -1,11 +1,13 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::types::{
     entities::{
-        Application, Attachment, Channel, Emoji, GuildMember, PublicUser, RoleSubscriptionData,
-        Sticker, StickerItem, User,
+        Application, Attachment, Channel, Component, Emoji, GuildMember, PublicUser,
+        RoleSubscriptionData, Sticker, StickerItem, User,
     },
-    utils::Snowflake,
+    utils::{check_length, parse_content, ContentNode, Snowflake, Validate},
+    LimitsConfiguration, ValidationError,
 };
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
@@ -56,6 +58,9 @@ pub struct Message {
     pub referenced_message: Option<Box<Message>>,
     pub interaction: Option<MessageInteraction>,
     pub thread: Option<Channel>,
+    #[cfg(feature = "sqlx")]
+    pub components: Option<sqlx::types::Json<Vec<Component>>>,
+    #[cfg(not(feature = "sqlx"))]
     pub components: Option<Vec<Component>>,
     pub sticker_items: Option<Vec<StickerItem>>,
     pub stickers: Option<Vec<Sticker>>,
@@ -63,6 +68,17 @@ pub struct Message {
     pub role_subscription_data: Option<RoleSubscriptionData>,
 }
 
+impl Message {
+    /// Parses the content of the message into mentions, emoji, formatted text, code, quotes and
+    /// links, see [`parse_content`].
+    pub fn parsed_content(&self) -> Vec<ContentNode> {
+        self.content
+            .as_deref()
+            .map(parse_content)
+            .unwrap_or_default()
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct MessageReference {
     pub message_id: Snowflake,
@@ -81,12 +97,64 @@ pub struct MessageInteraction {
     pub member: Option<GuildMember>,
 }
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// Which mentions in the content of a message notify the mentioned users. Mentions not allowed
+/// are still displayed, but do not ping anyone.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
 pub struct AllowedMention {
-    parse: Vec<AllowedMentionType>,
-    roles: Vec<Snowflake>,
-    users: Vec<Snowflake>,
-    replied_user: bool,
+    /// The types of mentions which are allowed regardless of the mentioned id. Must not contain
+    /// [`AllowedMentionType::Users`] or [`AllowedMentionType::Roles`] if `users` or `roles` are
+    /// given, respectively.
+    pub parse: Vec<AllowedMentionType>,
+    /// The roles which can be mentioned, at most 100.
+    pub roles: Vec<Snowflake>,
+    /// The users which can be mentioned, at most 100.
+    pub users: Vec<Snowflake>,
+    /// Whether the author of the message replied to is notified.
+    pub replied_user: bool,
+}
+
+impl AllowedMention {
+    /// Mentions do not notify anyone, not even the author of the message replied to.
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// All mentions notify the mentioned users, including `@everyone` and `@here`.
+    pub fn all() -> Self {
+        Self {
+            parse: vec![
+                AllowedMentionType::Users,
+                AllowedMentionType::Roles,
+                AllowedMentionType::Everyone,
+            ],
+            replied_user: true,
+            ..Default::default()
+        }
+    }
+
+    /// Only mentions of users notify them. Mentions of roles, `@everyone` and `@here` do not.
+    pub fn users_only() -> Self {
+        Self {
+            parse: vec![AllowedMentionType::Users],
+            ..Default::default()
+        }
+    }
+
+    /// Only mentions of the given users and roles notify them.
+    pub fn explicit(users: Vec<Snowflake>, roles: Vec<Snowflake>) -> Self {
+        Self {
+            users,
+            roles,
+            ..Default::default()
+        }
+    }
+
+    pub fn replied_user(self, replied_user: bool) -> Self {
+        Self {
+            replied_user,
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
@@ -106,74 +174,325 @@ pub struct ChannelMention {
     name: String,
 }
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Embed {
-    title: Option<String>,
+    pub title: Option<String>,
     #[serde(rename = "type")]
-    embed_type: Option<String>,
-    description: Option<String>,
-    url: Option<String>,
-    timestamp: Option<String>,
-    color: Option<i32>,
-    footer: Option<EmbedFooter>,
-    image: Option<EmbedImage>,
-    thumbnail: Option<EmbedThumbnail>,
-    video: Option<EmbedVideo>,
-    provider: Option<EmbedProvider>,
-    author: Option<EmbedAuthor>,
-    fields: Option<Vec<EmbedField>>,
+    pub embed_type: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub timestamp: Option<String>,
+    pub color: Option<i32>,
+    pub footer: Option<EmbedFooter>,
+    pub image: Option<EmbedImage>,
+    pub thumbnail: Option<EmbedThumbnail>,
+    pub video: Option<EmbedVideo>,
+    pub provider: Option<EmbedProvider>,
+    pub author: Option<EmbedAuthor>,
+    pub fields: Option<Vec<EmbedField>>,
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct EmbedFooter {
-    text: String,
-    icon_url: Option<String>,
-    proxy_icon_url: Option<String>,
+    pub text: String,
+    pub icon_url: Option<String>,
+    pub proxy_icon_url: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct EmbedImage {
-    url: String,
-    proxy_url: String,
-    height: Option<i32>,
-    width: Option<i32>,
+    pub url: String,
+    pub proxy_url: Option<String>,
+    pub height: Option<i32>,
+    pub width: Option<i32>,
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct EmbedThumbnail {
-    url: String,
-    proxy_url: Option<String>,
-    height: Option<i32>,
-    width: Option<i32>,
+    pub url: String,
+    pub proxy_url: Option<String>,
+    pub height: Option<i32>,
+    pub width: Option<i32>,
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
-struct EmbedVideo {
-    url: Option<String>,
-    proxy_url: Option<String>,
-    height: Option<i32>,
-    width: Option<i32>,
+pub struct EmbedVideo {
+    pub url: Option<String>,
+    pub proxy_url: Option<String>,
+    pub height: Option<i32>,
+    pub width: Option<i32>,
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct EmbedProvider {
-    name: Option<String>,
-    url: Option<String>,
+    pub name: Option<String>,
+    pub url: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct EmbedAuthor {
-    name: String,
-    url: Option<String>,
-    icon_url: Option<String>,
-    proxy_icon_url: Option<String>,
+    pub name: String,
+    pub url: Option<String>,
+    pub icon_url: Option<String>,
+    pub proxy_icon_url: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct EmbedField {
-    name: String,
-    value: String,
-    inline: Option<bool>,
+    pub name: String,
+    pub value: String,
+    pub inline: Option<bool>,
+}
+
+/// The maximum length of the title of an embed, and of the name of its author and fields.
+pub const MAX_EMBED_TITLE_LENGTH: u64 = 256;
+pub const MAX_EMBED_DESCRIPTION_LENGTH: u64 = 4096;
+pub const MAX_EMBED_FIELDS: usize = 25;
+pub const MAX_EMBED_FIELD_VALUE_LENGTH: u64 = 1024;
+pub const MAX_EMBED_FOOTER_LENGTH: u64 = 2048;
+/// The maximum length of all text of all embeds of a message combined.
+pub const MAX_EMBED_TOTAL_LENGTH: u64 = 6000;
+pub const MAX_EMBEDS: usize = 10;
+
+/// Embeds are built by chaining calls, starting with [`Embed::new`]:
+///
+/// ```
+/// # use chorus::types::{Embed, EmbedAuthor};
+/// let embed = Embed::new()
+///     .title("Release notes")
+///     .description("Embeds can now be built.")
+///     .color(0x5865F2)
+///     .field("Breaking changes", "None", true)
+///     .author(EmbedAuthor::new("polyphony"));
+/// ```
+///
+/// The limits on the lengths of the texts are checked when the message is sent, or when calling
+/// [`Validate::validate`].
+impl Embed {
+    pub fn new() -> Self {
+        Self {
+            embed_type: Some("rich".to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn title(self, title: &str) -> Self {
+        Self {
+            title: Some(title.to_string()),
+            ..self
+        }
+    }
+
+    pub fn description(self, description: &str) -> Self {
+        Self {
+            description: Some(description.to_string()),
+            ..self
+        }
+    }
+
+    /// The URL the title links to.
+    pub fn url(self, url: &str) -> Self {
+        Self {
+            url: Some(url.to_string()),
+            ..self
+        }
+    }
+
+    pub fn timestamp(self, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            timestamp: Some(timestamp.to_rfc3339()),
+            ..self
+        }
+    }
+
+    /// The color of the stripe on the side of the embed, as `0xRRGGBB`.
+    pub fn color(self, color: i32) -> Self {
+        Self {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    pub fn field(mut self, name: &str, value: &str, inline: bool) -> Self {
+        self.fields.get_or_insert_with(Vec::new).push(EmbedField {
+            name: name.to_string(),
+            value: value.to_string(),
+            inline: Some(inline),
+        });
+        self
+    }
+
+    pub fn author(self, author: EmbedAuthor) -> Self {
+        Self {
+            author: Some(author),
+            ..self
+        }
+    }
+
+    pub fn footer(self, footer: EmbedFooter) -> Self {
+        Self {
+            footer: Some(footer),
+            ..self
+        }
+    }
+
+    pub fn image(self, url: &str) -> Self {
+        Self {
+            image: Some(EmbedImage {
+                url: url.to_string(),
+                proxy_url: None,
+                height: None,
+                width: None,
+            }),
+            ..self
+        }
+    }
+
+    pub fn thumbnail(self, url: &str) -> Self {
+        Self {
+            thumbnail: Some(EmbedThumbnail {
+                url: url.to_string(),
+                proxy_url: None,
+                height: None,
+                width: None,
+            }),
+            ..self
+        }
+    }
+
+    /// The number of characters of all texts of the embed, which counts towards
+    /// [`MAX_EMBED_TOTAL_LENGTH`].
+    pub fn total_length(&self) -> u64 {
+        let length = |text: &str| text.chars().count() as u64;
+        let mut total = 0;
+        total += self.title.as_deref().map_or(0, length);
+        total += self.description.as_deref().map_or(0, length);
+        total += self
+            .footer
+            .as_ref()
+            .map_or(0, |footer| length(&footer.text));
+        total += self
+            .author
+            .as_ref()
+            .map_or(0, |author| length(&author.name));
+        for field in self.fields.iter().flatten() {
+            total += length(&field.name) + length(&field.value);
+        }
+        total
+    }
+}
+
+impl Validate for Embed {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(title) = &self.title {
+            check_length("embed title", title, 0, MAX_EMBED_TITLE_LENGTH)?;
+        }
+        if let Some(description) = &self.description {
+            check_length(
+                "embed description",
+                description,
+                0,
+                MAX_EMBED_DESCRIPTION_LENGTH,
+            )?;
+        }
+        if let Some(footer) = &self.footer {
+            check_length("embed footer", &footer.text, 0, MAX_EMBED_FOOTER_LENGTH)?;
+        }
+        if let Some(author) = &self.author {
+            check_length("embed author", &author.name, 0, MAX_EMBED_TITLE_LENGTH)?;
+        }
+        if let Some(fields) = &self.fields {
+            if fields.len() > MAX_EMBED_FIELDS {
+                return Err(ValidationError::Invalid {
+                    field: "embed fields",
+                    reason: format!(
+                        "an embed can have at most {} fields, but has {}",
+                        MAX_EMBED_FIELDS,
+                        fields.len()
+                    ),
+                });
+            }
+            for field in fields {
+                check_length("embed field name", &field.name, 1, MAX_EMBED_TITLE_LENGTH)?;
+                check_length(
+                    "embed field value",
+                    &field.value,
+                    1,
+                    MAX_EMBED_FIELD_VALUE_LENGTH,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Validate for Vec<Embed> {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if self.len() > MAX_EMBEDS {
+            return Err(ValidationError::Invalid {
+                field: "embeds",
+                reason: format!(
+                    "a message can have at most {} embeds, but has {}",
+                    MAX_EMBEDS,
+                    self.len()
+                ),
+            });
+        }
+        for embed in self {
+            embed.validate(limits)?;
+        }
+        let total = self.iter().map(Embed::total_length).sum();
+        if total > MAX_EMBED_TOTAL_LENGTH {
+            return Err(ValidationError::TooLong {
+                field: "embeds",
+                max: MAX_EMBED_TOTAL_LENGTH,
+                actual: total,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl EmbedFooter {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            icon_url: None,
+            proxy_icon_url: None,
+        }
+    }
+
+    pub fn icon_url(self, icon_url: &str) -> Self {
+        Self {
+            icon_url: Some(icon_url.to_string()),
+            ..self
+        }
+    }
+}
+
+impl EmbedAuthor {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            url: None,
+            icon_url: None,
+            proxy_icon_url: None,
+        }
+    }
+
+    pub fn url(self, url: &str) -> Self {
+        Self {
+            url: Some(url.to_string()),
+            ..self
+        }
+    }
+
+    pub fn icon_url(self, icon_url: &str) -> Self {
+        Self {
+            icon_url: Some(icon_url.to_string()),
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -183,18 +502,6 @@ pub struct Reaction {
     pub emoji: Emoji,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
-pub enum Component {
-    ActionRow = 1,
-    Button = 2,
-    StringSelect = 3,
-    TextInput = 4,
-    UserSelect = 5,
-    RoleSelect = 6,
-    MentionableSelect = 7,
-    ChannelSelect = 8,
-}
-
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct MessageActivity {
     #[serde(rename = "type")]
@@ -2,7 +2,9 @@ pub use application::*;
 pub use attachment::*;
 pub use audit_log::*;
 pub use auto_moderation::*;
+pub use backup_code::*;
 pub use channel::*;
+pub use component::*;
 pub use config::*;
 pub use emoji::*;
 pub use guild::*;
@@ -25,7 +27,9 @@ mod application;
 mod attachment;
 mod audit_log;
 mod auto_moderation;
+mod backup_code;
 mod channel;
+mod component;
 mod config;
 mod emoji;
 mod guild;
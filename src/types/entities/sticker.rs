@@ -28,3 +28,17 @@ pub struct StickerItem {
     pub name: String,
     pub format_type: u8,
 }
+
+/// A pack of standard stickers, which can be used by everyone.
+/// # Reference
+/// See [https://discord.com/developers/docs/resources/sticker#sticker-pack-object](https://discord.com/developers/docs/resources/sticker#sticker-pack-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StickerPack {
+    pub id: Snowflake,
+    pub stickers: Vec<Sticker>,
+    pub name: String,
+    pub sku_id: Option<Snowflake>,
+    pub cover_sticker_id: Option<Snowflake>,
+    pub description: Option<String>,
+    pub banner_asset_id: Option<Snowflake>,
+}
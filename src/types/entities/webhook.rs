@@ -13,11 +13,12 @@ pub struct Webhook {
     #[serde(rename = "type")]
     pub webhook_type: i32,
     pub name: String,
-    pub avatar: String,
-    pub token: String,
+    pub avatar: Option<String>,
+    /// Only set for incoming webhooks, when fetched by a user allowed to manage them.
+    pub token: Option<String>,
     pub guild_id: Snowflake,
     pub channel_id: Snowflake,
-    pub application_id: Snowflake,
+    pub application_id: Option<Snowflake>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "sqlx", sqlx(skip))]
     pub user: Option<User>,
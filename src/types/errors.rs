@@ -37,6 +37,34 @@ pub enum FieldFormatError {
     EmailError,
 }
 
+/// A request body which violates the limits or policies of an instance. Detected locally, before
+/// the request is sent.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ValidationError {
+    #[error("{field} must be at least {min} characters long, but is {actual} characters long")]
+    TooShort {
+        field: &'static str,
+        min: u64,
+        actual: u64,
+    },
+    #[error("{field} must be at most {max} characters long, but is {actual} characters long")]
+    TooLong {
+        field: &'static str,
+        max: u64,
+        actual: u64,
+    },
+    #[error("{field} must be at most {max} bytes large, but is {actual} bytes large")]
+    TooLarge {
+        field: &'static str,
+        max: u64,
+        actual: u64,
+    },
+    #[error("{field} is required")]
+    Missing { field: &'static str },
+    #[error("{field} is invalid: {reason}")]
+    Invalid { field: &'static str, reason: String },
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ErrorResponse {
     pub code: i32,
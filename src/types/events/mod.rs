@@ -95,3 +95,17 @@ pub struct GatewayReceivePayload<'a> {
 }
 
 impl<'a> WebSocketEvent for GatewayReceivePayload<'a> {}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+/// An owned copy of any event dispatched by the gateway (opcode 0), regardless of its type.
+///
+/// Unlike [GatewayReceivePayload], this does not borrow from the received message, which makes it
+/// possible to pass it on to other tasks. Useful when handling all events in one place.
+pub struct GatewayDispatch {
+    /// The name of the dispatched event, for example "MESSAGE_CREATE"
+    pub event_name: String,
+    pub sequence_number: Option<u64>,
+    pub event_data: Option<serde_json::Value>,
+}
+
+impl WebSocketEvent for GatewayDispatch {}
@@ -1,6 +1,12 @@
+use std::fmt;
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+use crate::types::utils::{check_length, Validate};
+use crate::types::{LimitsConfiguration, LoginResult, RegisterConfiguration, ValidationError};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub struct RegisterSchema {
     pub username: String,
@@ -15,7 +21,150 @@ pub struct RegisterSchema {
     pub promotional_email_opt_in: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+impl Validate for RegisterSchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_length(
+            "username",
+            &self.username,
+            2,
+            limits.user.max_username as u64,
+        )?;
+        if !self.consent {
+            return Err(ValidationError::Invalid {
+                field: "consent",
+                reason: "Consent must be 'true' to register.".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl RegisterSchema {
+    /// Checks the schema against the registration policies of an instance: The password
+    /// requirements, whether an email address is required and which domains it may have, and the
+    /// minimum age of new users.
+    ///
+    /// Instances do not publish their [`RegisterConfiguration`], so this is mostly useful to
+    /// servers, or clients which know the configuration of their instance.
+    pub fn validate_registration(
+        &self,
+        config: &RegisterConfiguration,
+    ) -> Result<(), ValidationError> {
+        if config.require_invite && self.invite.is_none() {
+            return Err(ValidationError::Missing { field: "invite" });
+        }
+        match &self.password {
+            Some(password) => validate_password(password, config)?,
+            None if config.password.required => {
+                return Err(ValidationError::Missing { field: "password" })
+            }
+            None => (),
+        }
+        match &self.email {
+            Some(email) => validate_email_domain(email, config)?,
+            None if config.email.required => {
+                return Err(ValidationError::Missing { field: "email" })
+            }
+            None => (),
+        }
+        match &self.date_of_birth {
+            Some(date_of_birth) => validate_age(date_of_birth, config)?,
+            None if config.date_of_birth.required => {
+                return Err(ValidationError::Missing {
+                    field: "date_of_birth",
+                })
+            }
+            None => (),
+        }
+        Ok(())
+    }
+}
+
+fn validate_password(
+    password: &str,
+    config: &RegisterConfiguration,
+) -> Result<(), ValidationError> {
+    let rules = &config.password;
+    check_length("password", password, rules.min_length as u64, 72)?;
+    let count = |predicate: fn(&char) -> bool| password.chars().filter(predicate).count();
+    let requirements = [
+        (count(char::is_ascii_digit), rules.min_numbers, "numbers"),
+        (
+            count(char::is_ascii_uppercase),
+            rules.min_upper_case,
+            "upper case letters",
+        ),
+        (
+            count(|c| !c.is_alphanumeric()),
+            rules.min_symbols,
+            "symbols",
+        ),
+    ];
+    for (actual, min, kind) in requirements {
+        if actual < min as usize {
+            return Err(ValidationError::Invalid {
+                field: "password",
+                reason: format!("Must contain at least {} {}", min, kind),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn validate_email_domain(
+    email: &str,
+    config: &RegisterConfiguration,
+) -> Result<(), ValidationError> {
+    let Some((_, domain)) = email.rsplit_once('@') else {
+        return Err(ValidationError::Invalid {
+            field: "email",
+            reason: "Not an email address".to_string(),
+        });
+    };
+    let listed = config
+        .email
+        .domains
+        .iter()
+        .any(|listed| listed.eq_ignore_ascii_case(domain));
+    if (config.email.allowlist && !listed)
+        || (!config.email.allowlist && config.email.blacklist && listed)
+    {
+        return Err(ValidationError::Invalid {
+            field: "email",
+            reason: format!("Email addresses of {} are not allowed", domain),
+        });
+    }
+    Ok(())
+}
+
+fn validate_age(
+    date_of_birth: &str,
+    config: &RegisterConfiguration,
+) -> Result<(), ValidationError> {
+    let date_of_birth = NaiveDate::parse_from_str(date_of_birth, "%Y-%m-%d").map_err(|e| {
+        ValidationError::Invalid {
+            field: "date_of_birth",
+            reason: e.to_string(),
+        }
+    })?;
+    let today = Utc::now().date_naive();
+    let mut age = today.year() - date_of_birth.year();
+    if (today.month(), today.day()) < (date_of_birth.month(), date_of_birth.day()) {
+        age -= 1;
+    }
+    if age < config.date_of_birth.minimum as i32 {
+        return Err(ValidationError::Invalid {
+            field: "date_of_birth",
+            reason: format!(
+                "Must be at least {} years old",
+                config.date_of_birth.minimum
+            ),
+        });
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub struct LoginSchema {
     /// For Discord, usernames must be between 2 and 32 characters,
@@ -30,11 +179,113 @@ pub struct LoginSchema {
     pub gift_code_sku_id: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Requests an email to reset the password of the account with the given email address or phone
+/// number.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ForgotPasswordSchema {
+    pub login: String,
+    pub captcha_key: Option<String>,
+}
+
+/// Sets a new password, using the token of a password reset email.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PasswordResetSchema {
+    pub password: String,
+    pub token: String,
+}
+
+/// Verifies an email address, using the token of a verification email.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VerifyEmailSchema {
+    pub token: String,
+    pub captcha_key: Option<String>,
+}
+
+/// Finishes logging in to an account with two-factor authentication enabled, using the ticket of
+/// the [`MfaRequiredResult`] and a TOTP or backup code.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub struct TotpSchema {
-    code: String,
-    ticket: String,
-    gift_code_sku_id: Option<String>,
-    login_source: Option<String>,
+    pub code: String,
+    pub ticket: String,
+    pub gift_code_sku_id: Option<String>,
+    pub login_source: Option<String>,
+}
+
+/// The response to a login request for an account with two-factor authentication enabled. The
+/// login has to be finished by sending the `ticket` along with a TOTP or backup code.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MfaRequiredResult {
+    pub ticket: String,
+    pub mfa: bool,
+    #[serde(default)]
+    pub sms: bool,
+}
+
+/// The response to a login request: Either the user is logged in, or a second factor is required.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LoginResponse {
+    Success(Box<LoginResult>),
+    MfaRequired(MfaRequiredResult),
+}
+
+/// The response of an instance with captchas enabled, if a captcha has to be solved before the
+/// request is accepted. The request has to be sent again, with the solution of the captcha as its
+/// `captcha_key`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CaptchaChallenge {
+    /// Why the request was rejected, e.g. `captcha-required` or `invalid-response`.
+    pub captcha_key: Vec<String>,
+    pub captcha_sitekey: Option<String>,
+    /// The captcha service to solve the captcha with, e.g. `hcaptcha` or `recaptcha`.
+    pub captcha_service: Option<String>,
+}
+
+impl fmt::Display for CaptchaChallenge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} (service: {}, sitekey: {})",
+            self.captcha_key.join(", "),
+            self.captcha_service.as_deref().unwrap_or("unknown"),
+            self.captcha_sitekey.as_deref().unwrap_or("none")
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LogoutSchema {
+    pub provider: Option<String>,
+    pub voip_provider: Option<String>,
+}
+
+/// Revokes the sessions with the given `id_hash`es.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SessionsLogoutSchema {
+    pub session_id_hashes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuthSessionsResponse {
+    pub user_sessions: Vec<AuthSession>,
+}
+
+/// A logged in session of the current user.
+///
+/// # Reference
+/// See <https://discord-userdoccers.vercel.app/resources/user#auth-session-object>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuthSession {
+    pub id_hash: String,
+    pub approx_last_used_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub client_info: AuthSessionClientInfo,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuthSessionClientInfo {
+    pub os: Option<String>,
+    pub platform: Option<String>,
+    pub location: Option<String>,
 }
@@ -1,15 +1,18 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{entities::PermissionOverwrite, Snowflake};
+use crate::types::utils::{check_image, check_length, Validate, MAX_NAME_LENGTH};
+use crate::types::{
+    entities::PermissionOverwrite, ImageData, LimitsConfiguration, Snowflake, ValidationError,
+};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct ChannelCreateSchema {
     pub name: String,
     #[serde(rename = "type")]
     pub channel_type: Option<u8>,
     pub topic: Option<String>,
-    pub icon: Option<String>,
+    pub icon: Option<ImageData>,
     pub bitrate: Option<i32>,
     pub user_limit: Option<i32>,
     pub rate_limit_per_user: Option<i32>,
@@ -32,7 +35,7 @@ pub struct ChannelModifySchema {
     pub name: Option<String>,
     pub channel_type: Option<u8>,
     pub topic: Option<String>,
-    pub icon: Option<String>,
+    pub icon: Option<ImageData>,
     pub bitrate: Option<i32>,
     pub user_limit: Option<i32>,
     pub rate_limit_per_user: Option<i32>,
@@ -94,3 +97,79 @@ impl GetChannelMessagesSchema {
         }
     }
 }
+
+impl Validate for ChannelCreateSchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_length("name", &self.name, 1, MAX_NAME_LENGTH)?;
+        if let Some(topic) = &self.topic {
+            check_length("topic", topic, 0, limits.channel.max_topic as u64)?;
+        }
+        if let Some(icon) = &self.icon {
+            check_image("icon", icon, limits)?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for ChannelModifySchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            check_length("name", name, 1, MAX_NAME_LENGTH)?;
+        }
+        if let Some(topic) = &self.topic {
+            check_length("topic", topic, 0, limits.channel.max_topic as u64)?;
+        }
+        if let Some(icon) = &self.icon {
+            check_image("icon", icon, limits)?;
+        }
+        Ok(())
+    }
+}
+
+/// The maximum number of members of a group DM, including its owner.
+pub const MAX_GROUP_DM_MEMBERS: usize = 10;
+
+/// Opens a DM with one recipient, or creates a group DM with several.
+/// # Reference
+/// See [https://discord.com/developers/docs/resources/user#create-dm](https://discord.com/developers/docs/resources/user#create-dm)
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PrivateChannelCreateSchema {
+    pub recipients: Vec<Snowflake>,
+}
+
+impl Validate for PrivateChannelCreateSchema {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if self.recipients.len() >= MAX_GROUP_DM_MEMBERS {
+            return Err(ValidationError::Invalid {
+                field: "recipients",
+                reason: format!(
+                    "a group DM can have at most {} recipients, but got {}",
+                    MAX_GROUP_DM_MEMBERS - 1,
+                    self.recipients.len()
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Renames a group DM or changes its icon. Fields which are `None` are left unchanged.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GroupDmModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<ImageData>,
+}
+
+impl Validate for GroupDmModifySchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            check_length("name", name, 1, MAX_NAME_LENGTH)?;
+        }
+        if let Some(icon) = &self.icon {
+            check_image("icon", icon, limits)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::utils::{check_image, check_length, check_size, Validate};
+use crate::types::{ImageData, LimitsConfiguration, Snowflake, ValidationError};
+
+/// The minimum and maximum length of the name of a custom emoji.
+pub const MIN_EMOJI_NAME_LENGTH: u64 = 2;
+pub const MAX_EMOJI_NAME_LENGTH: u64 = 32;
+
+/// The largest image which can be uploaded as an emoji, in bytes.
+pub const MAX_EMOJI_IMAGE_SIZE: u64 = 256 * 1024;
+
+/// Creates a custom emoji in a guild.
+/// # Reference
+/// See [https://discord.com/developers/docs/resources/emoji#create-guild-emoji](https://discord.com/developers/docs/resources/emoji#create-guild-emoji)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EmojiCreateSchema {
+    /// Only alphanumeric characters and underscores are allowed.
+    pub name: String,
+    pub image: ImageData,
+    /// The roles allowed to use the emoji. Everyone can use it if this is empty.
+    pub roles: Option<Vec<Snowflake>>,
+}
+
+impl Validate for EmojiCreateSchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_emoji_name(&self.name)?;
+        check_image("image", &self.image, limits)?;
+        check_size("image", self.image.size(), MAX_EMOJI_IMAGE_SIZE)
+    }
+}
+
+/// Renames a custom emoji or changes the roles allowed to use it. Fields which are `None` are left
+/// unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EmojiModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<Snowflake>>,
+}
+
+impl Validate for EmojiModifySchema {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            check_emoji_name(name)?;
+        }
+        Ok(())
+    }
+}
+
+fn check_emoji_name(name: &str) -> Result<(), ValidationError> {
+    check_length("name", name, MIN_EMOJI_NAME_LENGTH, MAX_EMOJI_NAME_LENGTH)?;
+    if !name
+        .chars()
+        .all(|char| char.is_ascii_alphanumeric() || char == '_')
+    {
+        return Err(ValidationError::Invalid {
+            field: "name",
+            reason: "only alphanumeric characters and underscores are allowed".to_string(),
+        });
+    }
+    Ok(())
+}
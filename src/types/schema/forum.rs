@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::entities::{Channel, Message};
+use crate::types::utils::{check_length, Validate};
+use crate::types::{
+    LimitsConfiguration, MessageSendSchema, Snowflake, ThreadCreateSchema, ValidationError,
+};
+
+/// The maximum number of tags which can be applied to a forum post.
+pub const MAX_APPLIED_TAGS: usize = 5;
+
+/// The maximum length of the name of a forum tag.
+pub const MAX_TAG_NAME_LENGTH: u64 = 20;
+
+/// Creates a post in a forum channel: a thread together with its starter message.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ForumPostCreateSchema {
+    pub name: String,
+    /// In minutes, one of [`crate::types::AUTO_ARCHIVE_DURATIONS`].
+    pub auto_archive_duration: Option<i32>,
+    pub rate_limit_per_user: Option<i32>,
+    /// The ids of the tags of the forum to apply to the post, at most [`MAX_APPLIED_TAGS`].
+    pub applied_tags: Option<Vec<Snowflake>>,
+    pub message: MessageSendSchema,
+}
+
+impl Validate for ForumPostCreateSchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        ThreadCreateSchema {
+            name: self.name.clone(),
+            auto_archive_duration: self.auto_archive_duration,
+            ..Default::default()
+        }
+        .validate(limits)?;
+        if let Some(tags) = &self.applied_tags {
+            check_applied_tags(tags)?;
+        }
+        self.message.validate(limits)
+    }
+}
+
+/// A forum post as returned when it is created: the thread, with its starter message.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ForumPostResponse {
+    #[serde(flatten)]
+    pub thread: Channel,
+    pub message: Option<Message>,
+}
+
+/// Creates or edits a tag of a forum channel.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct TagCreateSchema {
+    pub name: String,
+    /// Whether the tag can only be applied by members with the `MANAGE_THREADS` permission.
+    pub moderated: Option<bool>,
+    pub emoji_id: Option<Snowflake>,
+    pub emoji_name: Option<String>,
+}
+
+impl Validate for TagCreateSchema {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_length("name", &self.name, 1, MAX_TAG_NAME_LENGTH)
+    }
+}
+
+pub(crate) fn check_applied_tags(tags: &[Snowflake]) -> Result<(), ValidationError> {
+    if tags.len() > MAX_APPLIED_TAGS {
+        return Err(ValidationError::Invalid {
+            field: "applied_tags",
+            reason: format!(
+                "at most {} tags can be applied, but got {}",
+                MAX_APPLIED_TAGS,
+                tags.len()
+            ),
+        });
+    }
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::types::entities::Channel;
+use crate::types::utils::{check_image, Validate};
+use crate::types::{ImageData, LimitsConfiguration, ValidationError};
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -9,9 +11,18 @@ use crate::types::entities::Channel;
 pub struct GuildCreateSchema {
     pub name: Option<String>,
     pub region: Option<String>,
-    pub icon: Option<String>,
+    pub icon: Option<ImageData>,
     pub channels: Option<Vec<Channel>>,
     pub guild_template_code: Option<String>,
     pub system_channel_id: Option<String>,
     pub rules_channel_id: Option<String>,
 }
+
+impl Validate for GuildCreateSchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(icon) = &self.icon {
+            check_image("icon", icon, limits)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// The response of `/.well-known/spacebar`, which points to the API of the instance running on
+/// a domain.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct WellKnownResponse {
+    pub api: String,
+}
+
+/// The URLs an instance is reachable at, as returned by `/policies/instance/domains`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceDomainsResponse {
+    pub cdn: String,
+    pub gateway: Option<String>,
+    pub default_api_version: String,
+    pub api_endpoint: String,
+}
+
+/// The response of `/gateway`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct GatewayUrlResponse {
+    pub url: String,
+}
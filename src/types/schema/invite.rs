@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::utils::Validate;
+use crate::types::{LimitsConfiguration, ValidationError};
+
+/// The longest time an invite can be valid for, in seconds.
+pub const MAX_INVITE_AGE: u32 = 604800;
+
+/// The maximum number of times an invite with limited uses can be used.
+pub const MAX_INVITE_USES: u8 = 100;
+
+/// Creates an invite to a channel. Invites are valid for 24 hours and can be used any number of
+/// times by default.
+/// # Reference
+/// See [https://discord.com/developers/docs/resources/channel#create-channel-invite](https://discord.com/developers/docs/resources/channel#create-channel-invite)
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateChannelInviteSchema {
+    /// In seconds, at most [`MAX_INVITE_AGE`]. 0 for an invite which never expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age: Option<u32>,
+    /// At most [`MAX_INVITE_USES`]. 0 for unlimited uses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_uses: Option<u8>,
+    /// Whether members who joined with the invite are kicked when they disconnect, unless they
+    /// have been given a role.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary: Option<bool>,
+    /// Whether to always create a new invite, instead of reusing an existing one with the same
+    /// settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique: Option<bool>,
+}
+
+impl CreateChannelInviteSchema {
+    pub fn max_age(self, seconds: u32) -> Self {
+        Self {
+            max_age: Some(seconds),
+            ..self
+        }
+    }
+
+    pub fn max_uses(self, uses: u8) -> Self {
+        Self {
+            max_uses: Some(uses),
+            ..self
+        }
+    }
+
+    pub fn temporary(self, temporary: bool) -> Self {
+        Self {
+            temporary: Some(temporary),
+            ..self
+        }
+    }
+
+    pub fn unique(self, unique: bool) -> Self {
+        Self {
+            unique: Some(unique),
+            ..self
+        }
+    }
+}
+
+impl Validate for CreateChannelInviteSchema {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(max_age) = self.max_age {
+            if max_age > MAX_INVITE_AGE {
+                return Err(ValidationError::Invalid {
+                    field: "max_age",
+                    reason: format!(
+                        "must be at most {} seconds, but is {}",
+                        MAX_INVITE_AGE, max_age
+                    ),
+                });
+            }
+        }
+        if let Some(max_uses) = self.max_uses {
+            if max_uses > MAX_INVITE_USES {
+                return Err(ValidationError::Invalid {
+                    field: "max_uses",
+                    reason: format!("must be at most {}, but is {}", MAX_INVITE_USES, max_uses),
+                });
+            }
+        }
+        Ok(())
+    }
+}
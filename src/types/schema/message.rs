@@ -1,8 +1,12 @@
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::types::entities::{
-    AllowedMention, Component, Embed, MessageReference, PartialDiscordFileAttachment,
+    AllowedMention, Attachment, Component, Embed, Message, MessageReference,
+    PartialDiscordFileAttachment,
 };
+use crate::types::utils::{check_length, Snowflake, Validate};
+use crate::types::{LimitsConfiguration, ValidationError, MAX_STICKERS_PER_MESSAGE};
 
 #[derive(Debug, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -16,6 +20,325 @@ pub struct MessageSendSchema {
     pub allowed_mentions: Option<AllowedMention>,
     pub message_reference: Option<MessageReference>,
     pub components: Option<Vec<Component>>,
-    pub sticker_ids: Option<Vec<String>>,
+    /// The ids of at most [`crate::types::MAX_STICKERS_PER_MESSAGE`] stickers to send.
+    pub sticker_ids: Option<Vec<Snowflake>>,
     pub attachments: Option<Vec<PartialDiscordFileAttachment>>,
 }
+
+impl Validate for MessageSendSchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(content) = &self.content {
+            check_length("content", content, 0, limits.message.max_characters as u64)?;
+            // Instances which do not know about TTS messages do not limit them.
+            if self.tts == Some(true) && limits.message.max_tts_characters > 0 {
+                check_length(
+                    "content",
+                    content,
+                    0,
+                    limits.message.max_tts_characters as u64,
+                )?;
+            }
+        }
+        if let Some(embeds) = &self.embeds {
+            embeds.validate(limits)?;
+        }
+        if let Some(components) = &self.components {
+            components.validate(limits)?;
+        }
+        if let Some(attachments) = &self.attachments {
+            attachments.validate(limits)?;
+        }
+        if let Some(sticker_ids) = &self.sticker_ids {
+            if sticker_ids.len() > MAX_STICKERS_PER_MESSAGE {
+                return Err(ValidationError::Invalid {
+                    field: "sticker_ids",
+                    reason: format!(
+                        "at most {} stickers can be sent, but got {}",
+                        MAX_STICKERS_PER_MESSAGE,
+                        sticker_ids.len()
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How old a message may be at most to still be deleted in bulk, see [`MessageBulkDeleteSchema`].
+pub const MAX_BULK_DELETE_AGE_DAYS: i64 = 14;
+
+/// Edits the content, embeds, flags or attachments of a message. Fields which are `None` are left
+/// unchanged.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct MessageModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMention>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+    /// The attachments of the message which should be kept. Attachments of the message which are
+    /// not in this list are removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Attachment>>,
+}
+
+impl Validate for MessageModifySchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(content) = &self.content {
+            check_length("content", content, 0, limits.message.max_characters as u64)?;
+        }
+        if let Some(embeds) = &self.embeds {
+            embeds.validate(limits)?;
+        }
+        if let Some(components) = &self.components {
+            components.validate(limits)?;
+        }
+        Ok(())
+    }
+}
+
+/// Deletes multiple messages of a channel at once.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct MessageBulkDeleteSchema {
+    pub messages: Vec<Snowflake>,
+}
+
+impl Validate for MessageBulkDeleteSchema {
+    /// Between 2 and `maxBulkDelete` messages can be deleted at once, none of which may be older
+    /// than [`MAX_BULK_DELETE_AGE_DAYS`].
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        let count = self.messages.len() as u64;
+        if count < 2 {
+            return Err(ValidationError::Invalid {
+                field: "messages",
+                reason: format!("at least 2 messages must be deleted, but got {}", count),
+            });
+        }
+        if count > limits.message.max_bulk_delete as u64 {
+            return Err(ValidationError::Invalid {
+                field: "messages",
+                reason: format!(
+                    "at most {} messages can be deleted at once, but got {}",
+                    limits.message.max_bulk_delete, count
+                ),
+            });
+        }
+        let oldest_allowed = Utc::now() - Duration::days(MAX_BULK_DELETE_AGE_DAYS);
+        if let Some(message) = self
+            .messages
+            .iter()
+            .find(|message| message.timestamp() < oldest_allowed)
+        {
+            return Err(ValidationError::Invalid {
+                field: "messages",
+                reason: format!(
+                    "message {} is older than {} days",
+                    message, MAX_BULK_DELETE_AGE_DAYS
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The number of results per page of a message search, unless a different limit is set.
+pub const DEFAULT_SEARCH_LIMIT: u32 = 25;
+
+/// Something a message has to contain to be found by a [`MessageSearchQuery`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageSearchHas {
+    Link,
+    Embed,
+    File,
+    Image,
+    Video,
+    Sound,
+    Sticker,
+}
+
+impl MessageSearchHas {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MessageSearchHas::Link => "link",
+            MessageSearchHas::Embed => "embed",
+            MessageSearchHas::File => "file",
+            MessageSearchHas::Image => "image",
+            MessageSearchHas::Video => "video",
+            MessageSearchHas::Sound => "sound",
+            MessageSearchHas::Sticker => "sticker",
+        }
+    }
+}
+
+/// Filters for searching the messages of a guild or channel. All filters have to match for a
+/// message to be found; filters which can be given multiple times match if any of their values
+/// match.
+///
+/// ```
+/// # use chorus::types::{MessageSearchHas, MessageSearchQuery, Snowflake};
+/// # let author = Snowflake::generate();
+/// let query = MessageSearchQuery::new()
+///     .content("polyphony")
+///     .author(author)
+///     .has(MessageSearchHas::Link)
+///     .pinned(true);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MessageSearchQuery {
+    pub content: Option<String>,
+    pub author_ids: Vec<Snowflake>,
+    pub mentions: Vec<Snowflake>,
+    pub has: Vec<MessageSearchHas>,
+    pub channel_ids: Vec<Snowflake>,
+    pub pinned: Option<bool>,
+    /// Only messages with a larger id, i.e. sent later, are found.
+    pub min_id: Option<Snowflake>,
+    /// Only messages with a smaller id, i.e. sent earlier, are found.
+    pub max_id: Option<Snowflake>,
+    /// The number of results to skip, for pagination.
+    pub offset: Option<u32>,
+    /// The number of results per page, [`DEFAULT_SEARCH_LIMIT`] by default.
+    pub limit: Option<u32>,
+}
+
+impl MessageSearchQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn content(self, content: &str) -> Self {
+        Self {
+            content: Some(content.to_string()),
+            ..self
+        }
+    }
+
+    pub fn author(mut self, author_id: Snowflake) -> Self {
+        self.author_ids.push(author_id);
+        self
+    }
+
+    pub fn mentions(mut self, user_id: Snowflake) -> Self {
+        self.mentions.push(user_id);
+        self
+    }
+
+    pub fn has(mut self, has: MessageSearchHas) -> Self {
+        self.has.push(has);
+        self
+    }
+
+    pub fn channel(mut self, channel_id: Snowflake) -> Self {
+        self.channel_ids.push(channel_id);
+        self
+    }
+
+    pub fn pinned(self, pinned: bool) -> Self {
+        Self {
+            pinned: Some(pinned),
+            ..self
+        }
+    }
+
+    /// Only finds messages sent after `timestamp`.
+    pub fn after(self, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            min_id: Some(Snowflake::from_timestamp(timestamp)),
+            ..self
+        }
+    }
+
+    /// Only finds messages sent before `timestamp`.
+    pub fn before(self, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            max_id: Some(Snowflake::from_timestamp(timestamp)),
+            ..self
+        }
+    }
+
+    pub fn offset(self, offset: u32) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Must be between 1 and 25
+    pub fn limit(self, limit: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// The query for the page of results following the page of this query.
+    pub fn next_page(self) -> Self {
+        let offset = self.offset.unwrap_or(0) + self.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+        self.offset(offset)
+    }
+
+    /// The query string parameters of this query. Filters which are given multiple times are
+    /// repeated, which is why this cannot be done using serde.
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(content) = &self.content {
+            query.push(("content", content.clone()));
+        }
+        for author_id in &self.author_ids {
+            query.push(("author_id", author_id.to_string()));
+        }
+        for user_id in &self.mentions {
+            query.push(("mentions", user_id.to_string()));
+        }
+        for has in &self.has {
+            query.push(("has", has.as_str().to_string()));
+        }
+        for channel_id in &self.channel_ids {
+            query.push(("channel_id", channel_id.to_string()));
+        }
+        if let Some(pinned) = self.pinned {
+            query.push(("pinned", pinned.to_string()));
+        }
+        if let Some(min_id) = self.min_id {
+            query.push(("min_id", min_id.to_string()));
+        }
+        if let Some(max_id) = self.max_id {
+            query.push(("max_id", max_id.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            query.push(("offset", offset.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        query
+    }
+}
+
+/// One page of the results of a message search.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct MessageSearchResponse {
+    /// The messages found, each wrapped in a list of its own.
+    pub messages: Vec<Vec<Message>>,
+    /// The number of messages found in total, on all pages.
+    pub total_results: u64,
+}
+
+impl MessageSearchResponse {
+    /// The messages found.
+    pub fn hits(&self) -> impl Iterator<Item = &Message> {
+        self.messages.iter().flatten()
+    }
+
+    /// Whether there are more results after the page requested with `query`.
+    pub fn has_more(&self, query: &MessageSearchQuery) -> bool {
+        let seen = query.offset.unwrap_or(0) as u64 + self.messages.len() as u64;
+        !self.messages.is_empty() && seen < self.total_results
+    }
+}
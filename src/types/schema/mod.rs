@@ -1,17 +1,33 @@
 pub use apierror::*;
 pub use auth::*;
 pub use channel::*;
+pub use emoji::*;
+pub use forum::*;
 pub use guild::*;
+pub use instance::*;
+pub use invite::*;
 pub use message::*;
 pub use relationship::*;
 pub use role::*;
+pub use scheduled_event::*;
+pub use sticker::*;
+pub use thread::*;
 pub use user::*;
+pub use webhook::*;
 
 mod apierror;
 mod auth;
 mod channel;
+mod emoji;
+mod forum;
 mod guild;
+mod instance;
+mod invite;
 mod message;
 mod relationship;
 mod role;
+mod scheduled_event;
+mod sticker;
+mod thread;
 mod user;
+mod webhook;
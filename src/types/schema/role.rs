@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::utils::{check_image, check_length, Validate, MAX_NAME_LENGTH};
+use crate::types::{ImageData, LimitsConfiguration, ValidationError};
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 /// Represents the schema which needs to be sent to create or modify a Role.
@@ -9,7 +12,7 @@ pub struct RoleCreateModifySchema {
     pub permissions: Option<String>,
     pub color: Option<u32>,
     pub hoist: Option<bool>,
-    pub icon: Option<Vec<u8>>,
+    pub icon: Option<ImageData>,
     pub unicode_emoji: Option<String>,
     pub mentionable: Option<bool>,
     pub position: Option<i32>,
@@ -23,3 +26,15 @@ pub struct RolePositionUpdateSchema {
     pub id: String,
     pub position: u16,
 }
+
+impl Validate for RoleCreateModifySchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            check_length("name", name, 1, MAX_NAME_LENGTH)?;
+        }
+        if let Some(icon) = &self.icon {
+            check_image("icon", icon, limits)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::entities::{
+    GuildScheduledEventEntityMetadata, GuildScheduledEventEntityType,
+    GuildScheduledEventPrivacyLevel, GuildScheduledEventStatus,
+};
+use crate::types::utils::{check_image, check_length, Validate};
+use crate::types::{ImageData, LimitsConfiguration, Snowflake, ValidationError};
+
+/// The maximum length of the name of a scheduled event, and of the location of an external
+/// event.
+pub const MAX_SCHEDULED_EVENT_NAME_LENGTH: u64 = 100;
+
+/// The maximum length of the description of a scheduled event.
+pub const MAX_SCHEDULED_EVENT_DESCRIPTION_LENGTH: u64 = 1000;
+
+/// Schedules an event in a guild.
+///
+/// Events in a stage or voice channel require a `channel_id`. External events require the
+/// `location` in their `entity_metadata` and a `scheduled_end_time` instead.
+/// # Reference
+/// See [https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event](https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event)
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct GuildScheduledEventCreateSchema {
+    pub channel_id: Option<Snowflake>,
+    pub entity_metadata: Option<GuildScheduledEventEntityMetadata>,
+    pub name: String,
+    pub privacy_level: GuildScheduledEventPrivacyLevel,
+    pub scheduled_start_time: DateTime<Utc>,
+    pub scheduled_end_time: Option<DateTime<Utc>>,
+    pub description: Option<String>,
+    pub entity_type: GuildScheduledEventEntityType,
+    /// The cover image of the event.
+    pub image: Option<ImageData>,
+}
+
+impl GuildScheduledEventCreateSchema {
+    /// An event in a stage or voice channel.
+    pub fn in_channel(
+        name: &str,
+        entity_type: GuildScheduledEventEntityType,
+        channel_id: Snowflake,
+        scheduled_start_time: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            entity_type,
+            channel_id: Some(channel_id),
+            scheduled_start_time,
+            ..Default::default()
+        }
+    }
+
+    /// An event which takes place outside of the guild, at `location`.
+    pub fn external(
+        name: &str,
+        location: &str,
+        scheduled_start_time: DateTime<Utc>,
+        scheduled_end_time: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            entity_type: GuildScheduledEventEntityType::External,
+            entity_metadata: Some(GuildScheduledEventEntityMetadata {
+                location: Some(location.to_string()),
+            }),
+            scheduled_start_time,
+            scheduled_end_time: Some(scheduled_end_time),
+            ..Default::default()
+        }
+    }
+
+    pub fn description(self, description: &str) -> Self {
+        Self {
+            description: Some(description.to_string()),
+            ..self
+        }
+    }
+
+    pub fn image(self, image: ImageData) -> Self {
+        Self {
+            image: Some(image),
+            ..self
+        }
+    }
+}
+
+impl Validate for GuildScheduledEventCreateSchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_length("name", &self.name, 1, MAX_SCHEDULED_EVENT_NAME_LENGTH)?;
+        check_event_fields(
+            self.description.as_ref(),
+            self.image.as_ref(),
+            Some(self.scheduled_start_time),
+            self.scheduled_end_time,
+            limits,
+        )?;
+        check_entity(
+            self.entity_type,
+            self.channel_id,
+            self.entity_metadata.as_ref(),
+            self.scheduled_end_time,
+        )
+    }
+}
+
+/// Edits a scheduled event, or changes its status. Fields which are `None` are left unchanged.
+///
+/// When changing the `entity_type` to [`GuildScheduledEventEntityType::External`], the
+/// `entity_metadata` and `scheduled_end_time` have to be set as well.
+/// # Reference
+/// See [https://discord.com/developers/docs/resources/guild-scheduled-event#modify-guild-scheduled-event](https://discord.com/developers/docs/resources/guild-scheduled-event#modify-guild-scheduled-event)
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct GuildScheduledEventModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_metadata: Option<GuildScheduledEventEntityMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_level: Option<GuildScheduledEventPrivacyLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_start_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_end_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_type: Option<GuildScheduledEventEntityType>,
+    /// See [`GuildScheduledEventStatus::can_transition_to`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<GuildScheduledEventStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<ImageData>,
+}
+
+impl GuildScheduledEventModifySchema {
+    pub fn status(status: GuildScheduledEventStatus) -> Self {
+        Self {
+            status: Some(status),
+            ..Default::default()
+        }
+    }
+}
+
+impl Validate for GuildScheduledEventModifySchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            check_length("name", name, 1, MAX_SCHEDULED_EVENT_NAME_LENGTH)?;
+        }
+        check_event_fields(
+            self.description.as_ref(),
+            self.image.as_ref(),
+            self.scheduled_start_time,
+            self.scheduled_end_time,
+            limits,
+        )?;
+        if let Some(entity_type) = self.entity_type {
+            check_entity(
+                entity_type,
+                self.channel_id,
+                self.entity_metadata.as_ref(),
+                self.scheduled_end_time,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Paginates the users subscribed to a scheduled event, sorted by their id.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct GetScheduledEventUsersSchema {
+    /// At most 100, defaults to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u8>,
+    /// Whether to include the guild member of each user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_member: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Snowflake>,
+}
+
+fn check_event_fields(
+    description: Option<&String>,
+    image: Option<&ImageData>,
+    scheduled_start_time: Option<DateTime<Utc>>,
+    scheduled_end_time: Option<DateTime<Utc>>,
+    limits: &LimitsConfiguration,
+) -> Result<(), ValidationError> {
+    if let Some(description) = description {
+        check_length(
+            "description",
+            description,
+            0,
+            MAX_SCHEDULED_EVENT_DESCRIPTION_LENGTH,
+        )?;
+    }
+    if let Some(image) = image {
+        check_image("image", image, limits)?;
+    }
+    if let (Some(start), Some(end)) = (scheduled_start_time, scheduled_end_time) {
+        if end <= start {
+            return Err(ValidationError::Invalid {
+                field: "scheduled_end_time",
+                reason: "the event has to end after it starts".to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn check_entity(
+    entity_type: GuildScheduledEventEntityType,
+    channel_id: Option<Snowflake>,
+    entity_metadata: Option<&GuildScheduledEventEntityMetadata>,
+    scheduled_end_time: Option<DateTime<Utc>>,
+) -> Result<(), ValidationError> {
+    match entity_type {
+        GuildScheduledEventEntityType::External => {
+            let location = entity_metadata
+                .and_then(|metadata| metadata.location.as_ref())
+                .ok_or(ValidationError::Missing {
+                    field: "entity_metadata.location",
+                })?;
+            check_length(
+                "entity_metadata.location",
+                location,
+                1,
+                MAX_SCHEDULED_EVENT_NAME_LENGTH,
+            )?;
+            if scheduled_end_time.is_none() {
+                return Err(ValidationError::Missing {
+                    field: "scheduled_end_time",
+                });
+            }
+            if channel_id.is_some() {
+                return Err(ValidationError::Invalid {
+                    field: "channel_id",
+                    reason: "external events cannot take place in a channel".to_string(),
+                });
+            }
+        }
+        GuildScheduledEventEntityType::StageInstance | GuildScheduledEventEntityType::Voice => {
+            if channel_id.is_none() {
+                return Err(ValidationError::Missing {
+                    field: "channel_id",
+                });
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::utils::{check_length, check_size, Validate};
+use crate::types::{FileUpload, LimitsConfiguration, ValidationError};
+
+/// The minimum and maximum length of the name of a sticker.
+pub const MIN_STICKER_NAME_LENGTH: u64 = 2;
+pub const MAX_STICKER_NAME_LENGTH: u64 = 30;
+
+/// The maximum length of the description of a sticker. Descriptions which are not empty have to
+/// be at least 2 characters long.
+pub const MAX_STICKER_DESCRIPTION_LENGTH: u64 = 100;
+
+/// The maximum length of the autocomplete tags of a sticker.
+pub const MAX_STICKER_TAGS_LENGTH: u64 = 200;
+
+/// The largest file which can be uploaded as a sticker, in bytes.
+pub const MAX_STICKER_FILE_SIZE: u64 = 512 * 1024;
+
+/// The content types of the files which can be uploaded as a sticker: PNG, APNG, GIF and Lottie
+/// JSON.
+pub const STICKER_CONTENT_TYPES: [&str; 4] =
+    ["image/png", "image/apng", "image/gif", "application/json"];
+
+/// The maximum number of stickers which can be sent in one message.
+pub const MAX_STICKERS_PER_MESSAGE: usize = 3;
+
+/// Uploads a sticker to a guild. Unlike most schemas, this is sent as a multipart form.
+/// # Reference
+/// See [https://discord.com/developers/docs/resources/sticker#create-guild-sticker](https://discord.com/developers/docs/resources/sticker#create-guild-sticker)
+#[derive(Debug)]
+pub struct StickerCreateSchema {
+    pub name: String,
+    pub description: Option<String>,
+    /// Comma separated keywords used to suggest the sticker, usually including the name of a
+    /// unicode emoji.
+    pub tags: String,
+    pub file: FileUpload,
+}
+
+impl StickerCreateSchema {
+    pub fn new(name: &str, tags: &str, file: FileUpload) -> Self {
+        Self {
+            name: name.to_string(),
+            description: None,
+            tags: tags.to_string(),
+            file,
+        }
+    }
+
+    pub fn description(self, description: &str) -> Self {
+        Self {
+            description: Some(description.to_string()),
+            ..self
+        }
+    }
+}
+
+impl Validate for StickerCreateSchema {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_sticker_fields(
+            Some(&self.name),
+            self.description.as_ref(),
+            Some(&self.tags),
+        )?;
+        let content_type = self.file.mime_type();
+        if !STICKER_CONTENT_TYPES.contains(&content_type.as_str()) {
+            return Err(ValidationError::Invalid {
+                field: "file",
+                reason: format!("stickers of type {} are not accepted", content_type),
+            });
+        }
+        check_size("file", self.file.size(), MAX_STICKER_FILE_SIZE)
+    }
+}
+
+/// Changes the name, description or tags of a sticker. Fields which are `None` are left
+/// unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StickerModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+}
+
+impl Validate for StickerModifySchema {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_sticker_fields(
+            self.name.as_ref(),
+            self.description.as_ref(),
+            self.tags.as_ref(),
+        )
+    }
+}
+
+fn check_sticker_fields(
+    name: Option<&String>,
+    description: Option<&String>,
+    tags: Option<&String>,
+) -> Result<(), ValidationError> {
+    if let Some(name) = name {
+        check_length(
+            "name",
+            name,
+            MIN_STICKER_NAME_LENGTH,
+            MAX_STICKER_NAME_LENGTH,
+        )?;
+    }
+    if let Some(description) = description.filter(|description| !description.is_empty()) {
+        check_length(
+            "description",
+            description,
+            2,
+            MAX_STICKER_DESCRIPTION_LENGTH,
+        )?;
+    }
+    if let Some(tags) = tags {
+        check_length("tags", tags, 1, MAX_STICKER_TAGS_LENGTH)?;
+    }
+    Ok(())
+}
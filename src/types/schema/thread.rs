@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::entities::{Channel, ChannelType, ThreadMember};
+use crate::types::schema::forum::check_applied_tags;
+use crate::types::utils::{check_length, Validate, MAX_NAME_LENGTH};
+use crate::types::{LimitsConfiguration, Snowflake, ValidationError};
+
+/// The durations of inactivity, in minutes, after which a thread can be archived automatically.
+pub const AUTO_ARCHIVE_DURATIONS: [i32; 4] = [60, 1440, 4320, 10080];
+
+/// Starts a thread from an existing message.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct ThreadFromMessageCreateSchema {
+    pub name: String,
+    /// In minutes, one of [`AUTO_ARCHIVE_DURATIONS`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<i32>,
+}
+
+/// Starts a thread which is not attached to a message.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct ThreadCreateSchema {
+    pub name: String,
+    /// In minutes, one of [`AUTO_ARCHIVE_DURATIONS`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<i32>,
+    /// [`ChannelType::GuildPrivateThread`] by default.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub thread_type: Option<ChannelType>,
+    /// Whether members who are not moderators can add other members to a private thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<i32>,
+}
+
+/// Edits a thread. Fields which are `None` are left unchanged.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct ThreadModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    /// In minutes, one of [`AUTO_ARCHIVE_DURATIONS`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<i32>,
+    /// Locked threads can only be unarchived by moderators.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_tags: Option<Vec<Snowflake>>,
+}
+
+impl ThreadModifySchema {
+    pub fn archived(archived: bool) -> Self {
+        Self {
+            archived: Some(archived),
+            ..Default::default()
+        }
+    }
+
+    pub fn locked(locked: bool) -> Self {
+        Self {
+            locked: Some(locked),
+            ..Default::default()
+        }
+    }
+
+    pub fn auto_archive_duration(minutes: i32) -> Self {
+        Self {
+            auto_archive_duration: Some(minutes),
+            ..Default::default()
+        }
+    }
+}
+
+/// Paginates archived threads, which are sorted by the time they were archived, newest first.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct GetArchivedThreadsSchema {
+    /// Only threads archived before this ISO8601 timestamp are returned.
+    pub before: Option<String>,
+    pub limit: Option<i32>,
+}
+
+impl GetArchivedThreadsSchema {
+    pub fn before(timestamp: &str) -> Self {
+        Self {
+            before: Some(timestamp.to_string()),
+            limit: None,
+        }
+    }
+
+    pub fn limit(self, limit: i32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// The query for the page of threads following `response`, if there are any.
+    pub fn next_page(self, response: &ThreadsResponse) -> Option<Self> {
+        if !response.has_more {
+            return None;
+        }
+        let last = response
+            .threads
+            .iter()
+            .filter_map(|thread| thread.thread_metadata.as_ref())
+            .map(|metadata| metadata.archive_timestamp.clone())
+            .min()?;
+        Some(Self {
+            before: Some(last),
+            ..self
+        })
+    }
+}
+
+/// A list of threads, with the thread members of the current user for the threads they joined.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct ThreadsResponse {
+    pub threads: Vec<Channel>,
+    #[serde(default)]
+    pub members: Vec<ThreadMember>,
+    /// Whether there are more threads, only set when listing archived threads.
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+fn check_auto_archive_duration(duration: Option<i32>) -> Result<(), ValidationError> {
+    match duration {
+        Some(duration) if !AUTO_ARCHIVE_DURATIONS.contains(&duration) => {
+            Err(ValidationError::Invalid {
+                field: "auto_archive_duration",
+                reason: format!(
+                    "must be one of {:?} minutes, but is {}",
+                    AUTO_ARCHIVE_DURATIONS, duration
+                ),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+impl Validate for ThreadFromMessageCreateSchema {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_length("name", &self.name, 1, MAX_NAME_LENGTH)?;
+        check_auto_archive_duration(self.auto_archive_duration)
+    }
+}
+
+impl Validate for ThreadCreateSchema {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_length("name", &self.name, 1, MAX_NAME_LENGTH)?;
+        check_auto_archive_duration(self.auto_archive_duration)
+    }
+}
+
+impl Validate for ThreadModifySchema {
+    fn validate(&self, _limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            check_length("name", name, 1, MAX_NAME_LENGTH)?;
+        }
+        if let Some(tags) = &self.applied_tags {
+            check_applied_tags(tags)?;
+        }
+        check_auto_archive_duration(self.auto_archive_duration)
+    }
+}
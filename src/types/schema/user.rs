@@ -1,16 +1,96 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+use crate::types::utils::{check_image, check_length, Validate};
+use crate::types::{BackupCode, ImageData, LimitsConfiguration, User, ValidationError};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct UserModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
-    pub avatar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<ImageData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub accent_color: Option<u64>,
-    pub banner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner: Option<ImageData>,
+    #[serde(rename = "password")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub current_password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub new_password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub discriminator: Option<i16>,
 }
+
+impl Validate for UserModifySchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(username) = &self.username {
+            check_length("username", username, 2, limits.user.max_username as u64)?;
+        }
+        if let Some(avatar) = &self.avatar {
+            check_image("avatar", avatar, limits)?;
+        }
+        if let Some(banner) = &self.banner {
+            check_image("banner", banner, limits)?;
+        }
+        Ok(())
+    }
+}
+
+/// The response to modifying the current user. If the password has been changed, the response
+/// contains the new token of the user.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UserModifyResponse {
+    #[serde(flatten)]
+    pub user: User,
+    pub token: Option<String>,
+}
+
+/// Enables two-factor authentication using a TOTP authenticator.
+///
+/// `secret` is the base32 encoded secret shared with the authenticator, and `code` a code which
+/// the authenticator generated from it.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TotpEnableSchema {
+    pub password: String,
+    pub code: Option<String>,
+    pub secret: Option<String>,
+}
+
+/// The response to enabling two-factor authentication. The previous token of the user is
+/// invalidated and replaced by `token`.
+///
+/// `backup_codes` is empty, if the instance does not generate backup codes.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TotpEnableResponse {
+    pub token: String,
+    #[serde(default)]
+    pub backup_codes: Vec<BackupCode>,
+}
+
+/// Disables two-factor authentication. `code` is a TOTP or backup code.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TotpDisableSchema {
+    pub code: String,
+}
+
+/// Requests the backup codes of the current user. If `regenerate` is true, all previous backup
+/// codes are invalidated and replaced.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct BackupCodesSchema {
+    pub password: String,
+    pub regenerate: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct BackupCodesResponse {
+    #[serde(default)]
+    pub backup_codes: Vec<BackupCode>,
+}
@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::entities::{AllowedMention, Component, Embed, PartialDiscordFileAttachment};
+use crate::types::utils::{check_image, check_length, Validate};
+use crate::types::{ImageData, LimitsConfiguration, Snowflake, ValidationError};
+
+/// The maximum length of the name of a webhook, and of the username it can post as.
+pub const MAX_WEBHOOK_NAME_LENGTH: u64 = 80;
+
+/// Creates an incoming webhook in a channel.
+/// # Reference
+/// See [https://discord.com/developers/docs/resources/webhook#create-webhook](https://discord.com/developers/docs/resources/webhook#create-webhook)
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WebhookCreateSchema {
+    pub name: String,
+    pub avatar: Option<ImageData>,
+}
+
+impl Validate for WebhookCreateSchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_webhook_name("name", &self.name)?;
+        if let Some(avatar) = &self.avatar {
+            check_image("avatar", avatar, limits)?;
+        }
+        Ok(())
+    }
+}
+
+/// Edits a webhook. Fields which are `None` are left unchanged.
+///
+/// The `channel_id` can only be changed by a user, not with the token of the webhook.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WebhookModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<ImageData>,
+    /// The channel to move the webhook to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+}
+
+impl Validate for WebhookModifySchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(name) = &self.name {
+            check_webhook_name("name", name)?;
+        }
+        if let Some(avatar) = &self.avatar {
+            check_image("avatar", avatar, limits)?;
+        }
+        Ok(())
+    }
+}
+
+/// A message posted by a webhook. At least one of `content`, `embeds`, `components` or a file
+/// has to be sent.
+/// # Reference
+/// See [https://discord.com/developers/docs/resources/webhook#execute-webhook](https://discord.com/developers/docs/resources/webhook#execute-webhook)
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct WebhookExecuteSchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Overrides the name of the webhook for this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Overrides the avatar of the webhook for this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMention>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<PartialDiscordFileAttachment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+    /// Creates a post with this name, if the webhook belongs to a forum channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_name: Option<String>,
+}
+
+impl WebhookExecuteSchema {
+    pub fn content(content: &str) -> Self {
+        Self {
+            content: Some(content.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn username(self, username: &str) -> Self {
+        Self {
+            username: Some(username.to_string()),
+            ..self
+        }
+    }
+
+    pub fn avatar_url(self, avatar_url: &str) -> Self {
+        Self {
+            avatar_url: Some(avatar_url.to_string()),
+            ..self
+        }
+    }
+
+    pub fn embeds(self, embeds: Vec<Embed>) -> Self {
+        Self {
+            embeds: Some(embeds),
+            ..self
+        }
+    }
+}
+
+impl Validate for WebhookExecuteSchema {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        if let Some(content) = &self.content {
+            check_length("content", content, 0, limits.message.max_characters as u64)?;
+        }
+        if let Some(username) = &self.username {
+            check_webhook_name("username", username)?;
+        }
+        if let Some(embeds) = &self.embeds {
+            embeds.validate(limits)?;
+        }
+        if let Some(components) = &self.components {
+            components.validate(limits)?;
+        }
+        if let Some(thread_name) = &self.thread_name {
+            check_length("thread_name", thread_name, 1, 100)?;
+        }
+        Ok(())
+    }
+}
+
+/// The query parameters of executing a webhook.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WebhookExecuteQuery {
+    /// Whether to wait until the message is posted and return it. Otherwise, the message is
+    /// posted asynchronously and nothing is returned.
+    pub wait: bool,
+    /// The thread of the channel of the webhook to post the message in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<Snowflake>,
+}
+
+impl WebhookExecuteQuery {
+    pub fn wait() -> Self {
+        Self {
+            wait: true,
+            thread_id: None,
+        }
+    }
+
+    pub fn thread(self, thread_id: Snowflake) -> Self {
+        Self {
+            thread_id: Some(thread_id),
+            ..self
+        }
+    }
+}
+
+fn check_webhook_name(field: &'static str, name: &str) -> Result<(), ValidationError> {
+    check_length(field, name, 1, MAX_WEBHOOK_NAME_LENGTH)?;
+    if name.to_lowercase().contains("clyde") {
+        return Err(ValidationError::Invalid {
+            field,
+            reason: "the name cannot contain \"clyde\"".to_string(),
+        });
+    }
+    Ok(())
+}
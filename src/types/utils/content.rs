@@ -0,0 +1,656 @@
+use chrono::{DateTime, Utc};
+use regex::Captures;
+
+use crate::types::utils::regexes::{
+    CHANNEL_MENTION, CUSTOM_EMOJI, EVERYONE_MENTION, HERE_MENTION, MASKED_LINK, NO_EMBED_URL,
+    ROLE_MENTION, TIMESTAMP, URL, USER_MENTION,
+};
+use crate::types::utils::Snowflake;
+
+/// A part of the content of a message, see [`parse_content`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentNode {
+    Text(String),
+    UserMention(Snowflake),
+    RoleMention(Snowflake),
+    ChannelMention(Snowflake),
+    EveryoneMention,
+    HereMention,
+    CustomEmoji {
+        name: String,
+        id: Snowflake,
+        animated: bool,
+    },
+    /// A timestamp, displayed in the timezone of the reader.
+    Timestamp {
+        /// Seconds since the unix epoch.
+        timestamp: i64,
+        style: Option<TimestampStyle>,
+    },
+    Bold(Vec<ContentNode>),
+    Italic(Vec<ContentNode>),
+    Underline(Vec<ContentNode>),
+    Strikethrough(Vec<ContentNode>),
+    Spoiler(Vec<ContentNode>),
+    InlineCode(String),
+    CodeBlock {
+        language: Option<String>,
+        code: String,
+    },
+    Quote(Vec<ContentNode>),
+    Link {
+        url: String,
+        /// The text shown instead of the URL, for masked links.
+        label: Option<Vec<ContentNode>>,
+        /// Whether the link is wrapped in `<>` so that no embed is generated for it.
+        suppress_embed: bool,
+    },
+}
+
+/// How a timestamp is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampStyle {
+    /// `16:20`
+    ShortTime,
+    /// `16:20:30`
+    LongTime,
+    /// `20/04/2021`
+    ShortDate,
+    /// `20 April 2021`
+    LongDate,
+    /// `20 April 2021 16:20`, the default.
+    ShortDateTime,
+    /// `Tuesday, 20 April 2021 16:20`
+    LongDateTime,
+    /// `2 months ago`
+    Relative,
+}
+
+impl TimestampStyle {
+    pub fn from_char(style: char) -> Option<Self> {
+        Some(match style {
+            't' => TimestampStyle::ShortTime,
+            'T' => TimestampStyle::LongTime,
+            'd' => TimestampStyle::ShortDate,
+            'D' => TimestampStyle::LongDate,
+            'f' => TimestampStyle::ShortDateTime,
+            'F' => TimestampStyle::LongDateTime,
+            'R' => TimestampStyle::Relative,
+            _ => return None,
+        })
+    }
+
+    pub fn as_char(&self) -> char {
+        match self {
+            TimestampStyle::ShortTime => 't',
+            TimestampStyle::LongTime => 'T',
+            TimestampStyle::ShortDate => 'd',
+            TimestampStyle::LongDate => 'D',
+            TimestampStyle::ShortDateTime => 'f',
+            TimestampStyle::LongDateTime => 'F',
+            TimestampStyle::Relative => 'R',
+        }
+    }
+}
+
+/// Wraps formatted text in the [`ContentNode`] of its formatting.
+type FormattingNode = fn(Vec<ContentNode>) -> ContentNode;
+
+/// The delimiters of formatted text, in the order they are tried.
+const DELIMITERS: [(&str, FormattingNode); 6] = [
+    ("**", ContentNode::Bold),
+    ("__", ContentNode::Underline),
+    ("~~", ContentNode::Strikethrough),
+    ("||", ContentNode::Spoiler),
+    ("*", ContentNode::Italic),
+    ("_", ContentNode::Italic),
+];
+
+/// The characters which have to be escaped with a backslash to be displayed as they are.
+const MARKDOWN_CHARACTERS: [char; 10] = ['\\', '*', '_', '~', '`', '|', '>', '<', '[', ']'];
+
+/// Parses the content of a message into a tree of mentions, emoji, formatted text, code, quotes
+/// and links, the way it is displayed by clients. Text which cannot be parsed, for example
+/// because a delimiter is never closed, is kept as it is.
+pub fn parse_content(content: &str) -> Vec<ContentNode> {
+    let mut nodes = Vec::new();
+    let mut text = String::new();
+    let mut index = 0;
+    let mut previous = None;
+    while index < content.len() {
+        let rest = &content[index..];
+        if let Some((node, length)) = parse_node(rest, previous) {
+            match node {
+                ContentNode::Text(literal) => text.push_str(&literal),
+                node => {
+                    if !text.is_empty() {
+                        nodes.push(ContentNode::Text(std::mem::take(&mut text)));
+                    }
+                    nodes.push(node);
+                }
+            }
+            previous = content[..index + length].chars().next_back();
+            index += length;
+            continue;
+        }
+        let mut chars = rest.chars();
+        let character = chars.next().unwrap();
+        let escaped = chars
+            .next()
+            .filter(|escaped| escaped.is_ascii_punctuation());
+        match escaped {
+            Some(escaped) if character == '\\' => {
+                text.push(escaped);
+                index += 1 + escaped.len_utf8();
+                previous = Some(escaped);
+            }
+            _ => {
+                text.push(character);
+                index += character.len_utf8();
+                previous = Some(character);
+            }
+        }
+    }
+    if !text.is_empty() {
+        nodes.push(ContentNode::Text(text));
+    }
+    nodes
+}
+
+/// Escapes all markdown in `text`, so that it is displayed as it is. Mentions of `@everyone` and
+/// `@here` are broken up with a zero width space, so that they do not notify anyone.
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        if MARKDOWN_CHARACTERS.contains(&character) {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+        .replace("@everyone", "@\u{200B}everyone")
+        .replace("@here", "@\u{200B}here")
+}
+
+/// Tries to parse a node at the start of `rest`, returning it and the number of bytes it spans.
+/// `previous` is the character before `rest`.
+fn parse_node(rest: &str, previous: Option<char>) -> Option<(ContentNode, usize)> {
+    if previous.is_none() || previous == Some('\n') {
+        if let Some(quoted) = rest.strip_prefix(">>> ") {
+            return Some((ContentNode::Quote(parse_content(quoted)), rest.len()));
+        }
+        if let Some(quoted) = rest.strip_prefix("> ") {
+            let line = quoted.split('\n').next().unwrap();
+            return Some((ContentNode::Quote(parse_content(line)), 2 + line.len()));
+        }
+    }
+    if rest.starts_with('`') {
+        return Some(parse_code(rest));
+    }
+    for (delimiter, node) in DELIMITERS {
+        if rest.starts_with(delimiter) {
+            if let Some(parsed) = parse_delimited(rest, delimiter, node, previous) {
+                return Some(parsed);
+            }
+        }
+    }
+    match rest.chars().next()? {
+        '<' => parse_angle_brackets(rest),
+        '@' => {
+            if EVERYONE_MENTION.captures(rest).is_some() {
+                Some((ContentNode::EveryoneMention, "@everyone".len()))
+            } else if HERE_MENTION.captures(rest).is_some() {
+                Some((ContentNode::HereMention, "@here".len()))
+            } else {
+                None
+            }
+        }
+        '[' => {
+            let captures = MASKED_LINK.captures(rest)?;
+            Some((
+                ContentNode::Link {
+                    url: captures[2].to_string(),
+                    label: Some(parse_content(&captures[1])),
+                    suppress_embed: captures[0].contains('<'),
+                },
+                captures[0].len(),
+            ))
+        }
+        'h' if rest.starts_with("http://") || rest.starts_with("https://") => {
+            let captures = URL.captures(rest)?;
+            Some((
+                ContentNode::Link {
+                    url: captures[0].to_string(),
+                    label: None,
+                    suppress_embed: false,
+                },
+                captures[0].len(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Parses inline code or a fenced code block. Unclosed backticks are returned as text, so that
+/// they are not mistaken for the start of a shorter code span later on.
+fn parse_code(rest: &str) -> (ContentNode, usize) {
+    let ticks = rest.len() - rest.trim_start_matches('`').len();
+    if ticks >= 3 {
+        if let Some(end) = rest[3..].find("```") {
+            let block = &rest[3..3 + end];
+            let (language, code) = match block.split_once('\n') {
+                Some((language, code))
+                    if !language.is_empty() && !language.contains(char::is_whitespace) =>
+                {
+                    (Some(language.to_string()), code)
+                }
+                _ => (None, block),
+            };
+            return (
+                ContentNode::CodeBlock {
+                    language,
+                    code: code.to_string(),
+                },
+                end + 6,
+            );
+        }
+    }
+    let body = &rest[ticks..];
+    let mut search = 0;
+    while let Some(found) = body[search..].find('`') {
+        let start = search + found;
+        let run = body[start..].len() - body[start..].trim_start_matches('`').len();
+        if run == ticks && start > 0 {
+            let mut code = &body[..start];
+            if code.len() >= 2
+                && code.starts_with(' ')
+                && code.ends_with(' ')
+                && !code.trim().is_empty()
+            {
+                code = &code[1..code.len() - 1];
+            }
+            return (ContentNode::InlineCode(code.to_string()), start + 2 * ticks);
+        }
+        search = start + run;
+    }
+    (ContentNode::Text(rest[..ticks].to_string()), ticks)
+}
+
+fn parse_delimited(
+    rest: &str,
+    delimiter: &str,
+    node: FormattingNode,
+    previous: Option<char>,
+) -> Option<(ContentNode, usize)> {
+    let body = &rest[delimiter.len()..];
+    if delimiter.len() == 1 && body.starts_with(char::is_whitespace) {
+        return None;
+    }
+    // Underscores within words, as in snake_case, do not start italic text.
+    let within_word = |character: Option<char>| character.is_some_and(char::is_alphanumeric);
+    if delimiter == "_" && within_word(previous) {
+        return None;
+    }
+    let end = find_closing(body, delimiter)?;
+    if end == 0 || (delimiter == "_" && within_word(body[end + 1..].chars().next())) {
+        return None;
+    }
+    Some((node(parse_content(&body[..end])), 2 * delimiter.len() + end))
+}
+
+/// Finds the closing `delimiter` in `body`, skipping escaped characters and, for single character
+/// delimiters, doubled delimiters of nested formatting.
+fn find_closing(body: &str, delimiter: &str) -> Option<usize> {
+    let mut index = 0;
+    while index < body.len() {
+        let rest = &body[index..];
+        if let Some(escaped) = rest.strip_prefix('\\') {
+            index += 1 + escaped.chars().next().map_or(0, char::len_utf8);
+            continue;
+        }
+        if rest.starts_with(delimiter) {
+            if delimiter.len() == 1 && rest[1..].starts_with(delimiter) {
+                index += 2;
+                continue;
+            }
+            return Some(index);
+        }
+        index += rest.chars().next().unwrap().len_utf8();
+    }
+    None
+}
+
+fn parse_angle_brackets(rest: &str) -> Option<(ContentNode, usize)> {
+    if let Some(captures) = USER_MENTION.captures(rest) {
+        let node = ContentNode::UserMention(snowflake(&captures, 1)?);
+        return Some((node, captures[0].len()));
+    }
+    if let Some(captures) = ROLE_MENTION.captures(rest) {
+        let node = ContentNode::RoleMention(snowflake(&captures, 1)?);
+        return Some((node, captures[0].len()));
+    }
+    if let Some(captures) = CHANNEL_MENTION.captures(rest) {
+        let node = ContentNode::ChannelMention(snowflake(&captures, 1)?);
+        return Some((node, captures[0].len()));
+    }
+    if let Some(captures) = CUSTOM_EMOJI.captures(rest) {
+        let node = ContentNode::CustomEmoji {
+            name: captures[2].to_string(),
+            id: snowflake(&captures, 3)?,
+            animated: captures.get(1).is_some(),
+        };
+        return Some((node, captures[0].len()));
+    }
+    if let Some(captures) = TIMESTAMP.captures(rest) {
+        let node = ContentNode::Timestamp {
+            timestamp: captures[1].parse().ok()?,
+            style: captures
+                .get(2)
+                .and_then(|style| style.as_str().chars().next())
+                .and_then(TimestampStyle::from_char),
+        };
+        return Some((node, captures[0].len()));
+    }
+    if let Some(captures) = NO_EMBED_URL.captures(rest) {
+        let node = ContentNode::Link {
+            url: captures[1].to_string(),
+            label: None,
+            suppress_embed: true,
+        };
+        return Some((node, captures[0].len()));
+    }
+    None
+}
+
+fn snowflake(captures: &Captures, group: usize) -> Option<Snowflake> {
+    captures[group].parse::<u64>().ok().map(Snowflake::from)
+}
+
+/// Builds message content from parts, escaping all text so that it is displayed as it is.
+///
+/// ```
+/// # use chorus::types::{ContentBuilder, Snowflake};
+/// # let user_id = Snowflake::generate();
+/// let content = ContentBuilder::new()
+///     .user_mention(user_id)
+///     .text(" has reached level ")
+///     .bold("*5*")
+///     .build();
+/// assert_eq!(content, format!("<@{}> has reached level **\\*5\\***", user_id));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ContentBuilder {
+    content: String,
+}
+
+impl ContentBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends text, with all markdown escaped.
+    pub fn text(mut self, text: &str) -> Self {
+        self.content.push_str(&escape_markdown(text));
+        self
+    }
+
+    /// Appends markdown as it is, without escaping it.
+    pub fn raw(mut self, markdown: &str) -> Self {
+        self.content.push_str(markdown);
+        self
+    }
+
+    pub fn newline(mut self) -> Self {
+        self.content.push('\n');
+        self
+    }
+
+    pub fn bold(self, text: &str) -> Self {
+        self.wrapped("**", text)
+    }
+
+    pub fn italic(self, text: &str) -> Self {
+        self.wrapped("*", text)
+    }
+
+    pub fn underline(self, text: &str) -> Self {
+        self.wrapped("__", text)
+    }
+
+    pub fn strikethrough(self, text: &str) -> Self {
+        self.wrapped("~~", text)
+    }
+
+    pub fn spoiler(self, text: &str) -> Self {
+        self.wrapped("||", text)
+    }
+
+    /// Appends inline code, using more backticks than `code` contains in a row.
+    pub fn code(mut self, code: &str) -> Self {
+        let mut longest = 0;
+        let mut run = 0;
+        for character in code.chars() {
+            run = if character == '`' { run + 1 } else { 0 };
+            longest = longest.max(run);
+        }
+        let fence = "`".repeat(longest + 1);
+        let padding = if code.starts_with('`') || code.ends_with('`') {
+            " "
+        } else {
+            ""
+        };
+        self.content
+            .push_str(&format!("{fence}{padding}{code}{padding}{fence}"));
+        self
+    }
+
+    /// Appends a fenced code block. Fences within `code` are broken up with a zero width space.
+    pub fn code_block(mut self, language: Option<&str>, code: &str) -> Self {
+        self.start_line();
+        self.content.push_str("```");
+        self.content.push_str(language.unwrap_or_default());
+        self.content.push('\n');
+        self.content.push_str(&code.replace("```", "`\u{200B}``"));
+        if !code.ends_with('\n') {
+            self.content.push('\n');
+        }
+        self.content.push_str("```");
+        self
+    }
+
+    /// Appends a quote on a new line. Each line of `text` is quoted.
+    pub fn quote(mut self, text: &str) -> Self {
+        self.start_line();
+        let lines: Vec<String> = text
+            .split('\n')
+            .map(|line| format!("> {}", escape_markdown(line)))
+            .collect();
+        self.content.push_str(&lines.join("\n"));
+        self.content.push('\n');
+        self
+    }
+
+    /// Appends a masked link, showing `label` instead of the URL.
+    pub fn link(mut self, label: &str, url: &str) -> Self {
+        let url = url.replace('(', "%28").replace(')', "%29");
+        self.content
+            .push_str(&format!("[{}]({})", escape_markdown(label), url));
+        self
+    }
+
+    pub fn user_mention(mut self, user_id: Snowflake) -> Self {
+        self.content.push_str(&format!("<@{}>", user_id));
+        self
+    }
+
+    pub fn role_mention(mut self, role_id: Snowflake) -> Self {
+        self.content.push_str(&format!("<@&{}>", role_id));
+        self
+    }
+
+    pub fn channel_mention(mut self, channel_id: Snowflake) -> Self {
+        self.content.push_str(&format!("<#{}>", channel_id));
+        self
+    }
+
+    pub fn custom_emoji(mut self, name: &str, id: Snowflake, animated: bool) -> Self {
+        let prefix = if animated { "a" } else { "" };
+        self.content
+            .push_str(&format!("<{}:{}:{}>", prefix, name, id));
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: DateTime<Utc>, style: Option<TimestampStyle>) -> Self {
+        match style {
+            Some(style) => self.content.push_str(&format!(
+                "<t:{}:{}>",
+                timestamp.timestamp(),
+                style.as_char()
+            )),
+            None => self
+                .content
+                .push_str(&format!("<t:{}>", timestamp.timestamp())),
+        }
+        self
+    }
+
+    pub fn build(self) -> String {
+        self.content
+    }
+
+    fn wrapped(mut self, delimiter: &str, text: &str) -> Self {
+        self.content.push_str(delimiter);
+        self.content.push_str(&escape_markdown(text));
+        self.content.push_str(delimiter);
+        self
+    }
+
+    fn start_line(&mut self) {
+        if !self.content.is_empty() && !self.content.ends_with('\n') {
+            self.content.push('\n');
+        }
+    }
+}
+
+#[cfg(test)]
+mod content {
+    use super::*;
+
+    #[test]
+    fn mentions_and_emoji() {
+        let nodes = parse_content("Hi <@!1234> and <@&5678>, see <#42> <a:party:99> @everyone");
+        assert_eq!(
+            nodes,
+            vec![
+                ContentNode::Text("Hi ".to_string()),
+                ContentNode::UserMention(Snowflake::from(1234)),
+                ContentNode::Text(" and ".to_string()),
+                ContentNode::RoleMention(Snowflake::from(5678)),
+                ContentNode::Text(", see ".to_string()),
+                ContentNode::ChannelMention(Snowflake::from(42)),
+                ContentNode::Text(" ".to_string()),
+                ContentNode::CustomEmoji {
+                    name: "party".to_string(),
+                    id: Snowflake::from(99),
+                    animated: true
+                },
+                ContentNode::Text(" ".to_string()),
+                ContentNode::EveryoneMention,
+            ]
+        );
+    }
+
+    #[test]
+    fn formatting() {
+        let nodes = parse_content("**bold *italic* text** and snake_case_name ||secret||");
+        assert_eq!(
+            nodes,
+            vec![
+                ContentNode::Bold(vec![
+                    ContentNode::Text("bold ".to_string()),
+                    ContentNode::Italic(vec![ContentNode::Text("italic".to_string())]),
+                    ContentNode::Text(" text".to_string()),
+                ]),
+                ContentNode::Text(" and snake_case_name ".to_string()),
+                ContentNode::Spoiler(vec![ContentNode::Text("secret".to_string())]),
+            ]
+        );
+        assert_eq!(
+            parse_content("**unclosed"),
+            vec![ContentNode::Text("**unclosed".to_string())]
+        );
+    }
+
+    #[test]
+    fn code_quotes_and_links() {
+        let nodes = parse_content(
+            "> quoted <t:1618953630:R>\n`a*b*` ``x`y``\n```rust\nlet a = 1;\n```[docs](https://example.org)",
+        );
+        assert_eq!(
+            nodes,
+            vec![
+                ContentNode::Quote(vec![
+                    ContentNode::Text("quoted ".to_string()),
+                    ContentNode::Timestamp {
+                        timestamp: 1618953630,
+                        style: Some(TimestampStyle::Relative)
+                    },
+                ]),
+                ContentNode::Text("\n".to_string()),
+                ContentNode::InlineCode("a*b*".to_string()),
+                ContentNode::Text(" ".to_string()),
+                ContentNode::InlineCode("x`y".to_string()),
+                ContentNode::Text("\n".to_string()),
+                ContentNode::CodeBlock {
+                    language: Some("rust".to_string()),
+                    code: "let a = 1;\n".to_string()
+                },
+                ContentNode::Link {
+                    url: "https://example.org".to_string(),
+                    label: Some(vec![ContentNode::Text("docs".to_string())]),
+                    suppress_embed: false
+                },
+            ]
+        );
+        assert_eq!(
+            parse_content("see https://example.org/a_b."),
+            vec![
+                ContentNode::Text("see ".to_string()),
+                ContentNode::Link {
+                    url: "https://example.org/a_b".to_string(),
+                    label: None,
+                    suppress_embed: false
+                },
+                ContentNode::Text(".".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unclosed_brackets() {
+        // Every bracket starts a failed match, which must not search the rest of the text.
+        let text = "<[<http://a".repeat(20_000);
+        assert_eq!(parse_content(&text), vec![ContentNode::Text(text.clone())]);
+    }
+
+    #[test]
+    fn escaping() {
+        let text = "**not bold** _nor_ `code` <@1234> > [x](y) \\ ||";
+        assert_eq!(
+            parse_content(&escape_markdown(text)),
+            vec![ContentNode::Text(text.to_string())]
+        );
+        assert!(!EVERYONE_MENTION.is_match(&escape_markdown("@everyone")));
+
+        let content = ContentBuilder::new()
+            .text("Use ")
+            .code("a ` b")
+            .quote("wise\nwords")
+            .build();
+        assert_eq!(content, "Use ``a ` b``\n> wise\n> words\n");
+        assert_eq!(
+            parse_content(&content)[1],
+            ContentNode::InlineCode("a ` b".to_string())
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use base64::Engine;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::utils::{check_size, Validate};
+use crate::types::{LimitsConfiguration, ValidationError};
+
+/// The largest image which can be set as an avatar, banner or icon, in bytes. Instances with a
+/// lower maximum attachment size reject images above that size instead.
+pub const MAX_IMAGE_DATA_SIZE: u64 = 10 * 1024 * 1024;
+
+/// The content types of the image formats instances accept for avatars, banners and icons.
+pub const IMAGE_CONTENT_TYPES: [&str; 4] = ["image/png", "image/jpeg", "image/gif", "image/webp"];
+
+/// An image to set as an avatar, banner or icon, such as [`UserModifySchema::avatar`](crate::types::UserModifySchema::avatar).
+/// Serialized as the `data:` URI the API expects.
+///
+/// The format of the image is detected from its content. Only PNG, JPEG, GIF and WebP images are
+/// accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageData {
+    content_type: String,
+    data: Vec<u8>,
+}
+
+impl ImageData {
+    /// # Errors
+    /// [`ValidationError::Invalid`] if `data` is not a PNG, JPEG, GIF or WebP image.
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, ValidationError> {
+        let content_type = sniff_content_type(&data).ok_or_else(|| ValidationError::Invalid {
+            field: "image",
+            reason: "the image must be a PNG, JPEG, GIF or WebP image".to_string(),
+        })?;
+        Ok(Self {
+            content_type: content_type.to_string(),
+            data,
+        })
+    }
+
+    /// Reads the image at `path`.
+    ///
+    /// # Errors
+    /// If the file cannot be read, or [`std::io::ErrorKind::InvalidData`] if it is not a PNG,
+    /// JPEG, GIF or WebP image.
+    pub async fn from_path(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let data = tokio::fs::read(path).await?;
+        Self::from_bytes(data)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))
+    }
+
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn size(&self) -> u64 {
+        self.data.len() as u64
+    }
+
+    /// The `data:` URI of the image, for example `data:image/png;base64,iVBORw0KGgo...`.
+    pub fn to_data_uri(&self) -> String {
+        format!(
+            "data:{};base64,{}",
+            self.content_type,
+            base64::engine::general_purpose::STANDARD.encode(&self.data)
+        )
+    }
+}
+
+fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
+
+/// Checks the size and format of an image set on a schema.
+pub(crate) fn check_image(
+    field: &'static str,
+    image: &ImageData,
+    limits: &LimitsConfiguration,
+) -> Result<(), ValidationError> {
+    if !IMAGE_CONTENT_TYPES.contains(&image.content_type.as_str()) {
+        return Err(ValidationError::Invalid {
+            field,
+            reason: format!("images of type {} are not accepted", image.content_type),
+        });
+    }
+    check_size(
+        field,
+        image.size(),
+        MAX_IMAGE_DATA_SIZE.min(limits.message.max_attachment_size),
+    )
+}
+
+impl Validate for ImageData {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError> {
+        check_image("image", self, limits)
+    }
+}
+
+impl FromStr for ImageData {
+    type Err = ValidationError;
+
+    /// Parses a base64 encoded `data:` URI.
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| ValidationError::Invalid {
+            field: "image",
+            reason: reason.to_string(),
+        };
+        let (content_type, data) = uri
+            .strip_prefix("data:")
+            .and_then(|uri| uri.split_once(";base64,"))
+            .ok_or_else(|| invalid("not a base64 encoded data URI"))?;
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .map_err(|error| invalid(&error.to_string()))?;
+        Ok(Self {
+            content_type: content_type.to_string(),
+            data,
+        })
+    }
+}
+
+impl fmt::Display for ImageData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_data_uri())
+    }
+}
+
+impl Serialize for ImageData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_data_uri())
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let uri = String::deserialize(deserializer)?;
+        uri.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod image {
+    use super::*;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    #[test]
+    fn data_uri() {
+        let image = ImageData::from_bytes(PNG.to_vec()).unwrap();
+        assert_eq!(image.content_type(), "image/png");
+        let uri = image.to_data_uri();
+        assert_eq!(uri, "data:image/png;base64,iVBORw0KGgoAAAANSUhEUg==");
+        assert_eq!(uri.parse::<ImageData>().unwrap(), image);
+        assert_eq!(
+            serde_json::to_string(&Some(image)).unwrap(),
+            format!("\"{}\"", uri)
+        );
+
+        let webp = b"RIFF\0\0\0\0WEBPVP8 ".to_vec();
+        assert_eq!(
+            ImageData::from_bytes(webp).unwrap().content_type(),
+            "image/webp"
+        );
+        assert!(ImageData::from_bytes(b"<svg></svg>".to_vec()).is_err());
+        assert!("image/png;base64,AAAA".parse::<ImageData>().is_err());
+    }
+
+    #[test]
+    fn validation() {
+        let mut limits = LimitsConfiguration::default();
+        let mut data = PNG.to_vec();
+        data.resize(2048, 0);
+        let image = ImageData::from_bytes(data).unwrap();
+        assert!(check_image("avatar", &image, &limits).is_ok());
+        limits.message.max_attachment_size = 1024;
+        assert_eq!(
+            check_image("avatar", &image, &limits),
+            Err(ValidationError::TooLarge {
+                field: "avatar",
+                max: 1024,
+                actual: 2048
+            })
+        );
+        let svg = "data:image/svg+xml;base64,AAAA".parse().unwrap();
+        assert!(matches!(
+            check_image("icon", &svg, &LimitsConfiguration::default()),
+            Err(ValidationError::Invalid { field: "icon", .. })
+        ));
+    }
+}
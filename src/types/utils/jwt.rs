@@ -1,8 +1,20 @@
-use jsonwebtoken::{encode, EncodingKey, Header};
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::{
+    decode, encode, Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::types::utils::Snowflake;
+use crate::types::SecurityConfiguration;
 
+/// How long a token issued by [`Claims::new`] is valid for.
+pub const DEFAULT_TOKEN_LIFETIME: i64 = 60 * 60 * 24;
+
+#[deprecated(
+    note = "signs with HS256 regardless of the configured `jwt_algorithm`, use `encode_token` instead"
+)]
+#[allow(deprecated)]
 pub fn generate_token(id: &Snowflake, email: String, jwt_key: &str) -> String {
     let claims = Claims::new(&email, id);
 
@@ -19,16 +31,59 @@ pub struct Claims {
 
 impl Claims {
     pub fn new(user: &str, id: &Snowflake) -> Self {
-        let unix = chrono::Utc::now().timestamp();
+        Self::with_lifetime(user, id, Duration::seconds(DEFAULT_TOKEN_LIFETIME))
+    }
+
+    /// Creates claims for a token which expires after `lifetime`.
+    pub fn with_lifetime(user: &str, id: &Snowflake, lifetime: Duration) -> Self {
+        let unix = Utc::now().timestamp();
         Self {
-            exp: unix + (60 * 60 * 24),
+            exp: unix + lifetime.num_seconds(),
             id: id.to_string(),
             iat: unix,
             email: user.to_string(),
         }
     }
+
+    /// Checks that the token was issued after `valid_tokens_since`, i.e. after the last password
+    /// change of the user, see [`crate::types::UserData`].
+    pub fn check_issued_after(&self, valid_tokens_since: DateTime<Utc>) -> Result<(), TokenError> {
+        if self.iat < valid_tokens_since.timestamp() {
+            return Err(TokenError::IssuedBeforePasswordChange);
+        }
+        Ok(())
+    }
+}
+
+/// Why a token was rejected.
+#[derive(Debug, thiserror::Error)]
+pub enum TokenError {
+    #[error("No token was provided")]
+    Missing,
+    #[error("The token has expired")]
+    Expired,
+    #[error("The token was issued before the last password change")]
+    IssuedBeforePasswordChange,
+    #[error("The user of the token does not exist")]
+    UnknownUser,
+    #[error("The algorithm {0:?} needs a key pair, not a secret")]
+    UnsupportedAlgorithm(Algorithm),
+    #[error("The token is invalid: {0}")]
+    Invalid(jsonwebtoken::errors::Error),
+}
+
+impl From<jsonwebtoken::errors::Error> for TokenError {
+    fn from(error: jsonwebtoken::errors::Error) -> Self {
+        match error.kind() {
+            ErrorKind::ExpiredSignature => TokenError::Expired,
+            _ => TokenError::Invalid(error),
+        }
+    }
 }
 
+#[deprecated(
+    note = "signs with HS256 regardless of the configured `jwt_algorithm`, use `encode_token` instead"
+)]
 pub fn build_token(claims: &Claims, jwt_key: &str) -> Result<String, jsonwebtoken::errors::Error> {
     encode(
         &Header::default(),
@@ -37,8 +92,164 @@ pub fn build_token(claims: &Claims, jwt_key: &str) -> Result<String, jsonwebtoke
     )
 }
 
-/*pub fn decode_token(token: &str) -> Result<TokenData<Claims>, Error> {
-    let mut validation = Validation::new(Algorithm::HS256);
-    validation.sub = Some("quartzauth".to_string());
-    decode(token, &DecodingKey::from_secret(JWT_SECRET), &validation)
-}*/
+/// Signs `claims` using the `jwt_algorithm` and `jwt_secret` of the [`SecurityConfiguration`].
+pub fn encode_token(claims: &Claims, config: &SecurityConfiguration) -> Result<String, TokenError> {
+    check_algorithm(config.jwt_algorithm)?;
+    Ok(encode(
+        &Header::new(config.jwt_algorithm),
+        claims,
+        &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+    )?)
+}
+
+/// Decodes a token and verifies its signature and expiry, using the `jwt_algorithm` and
+/// `jwt_secret` of the [`SecurityConfiguration`].
+///
+/// Whether the token has been issued before the last password change of the user is not checked,
+/// see [`verify_token`] and [`Claims::check_issued_after`].
+pub fn decode_token(token: &str, config: &SecurityConfiguration) -> Result<Claims, TokenError> {
+    check_algorithm(config.jwt_algorithm)?;
+    let validation = Validation::new(config.jwt_algorithm);
+    let data: TokenData<Claims> = decode(
+        token,
+        &DecodingKey::from_secret(config.jwt_secret.as_bytes()),
+        &validation,
+    )?;
+    Ok(data.claims)
+}
+
+/// Decodes and fully verifies a token: its signature and expiry, and that it was issued after
+/// `valid_tokens_since`, the last password change of the user.
+pub fn verify_token(
+    token: &str,
+    config: &SecurityConfiguration,
+    valid_tokens_since: DateTime<Utc>,
+) -> Result<Claims, TokenError> {
+    let claims = decode_token(token, config)?;
+    claims.check_issued_after(valid_tokens_since)?;
+    Ok(claims)
+}
+
+/// Tokens are signed with a shared secret, so only the HMAC algorithms can be used.
+fn check_algorithm(algorithm: Algorithm) -> Result<(), TokenError> {
+    match algorithm {
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => Ok(()),
+        _ => Err(TokenError::UnsupportedAlgorithm(algorithm)),
+    }
+}
+
+#[cfg(feature = "poem")]
+impl poem::error::ResponseError for TokenError {
+    fn status(&self) -> poem::http::StatusCode {
+        match self {
+            TokenError::UnsupportedAlgorithm(_) => poem::http::StatusCode::INTERNAL_SERVER_ERROR,
+            _ => poem::http::StatusCode::UNAUTHORIZED,
+        }
+    }
+}
+
+/// Looks up since when the tokens of a user are valid, i.e. the `valid_tokens_since` of their
+/// [`crate::types::UserData`], for the [`Claims`] extractor. It has to be added to the data of the
+/// request as an `Arc<dyn ValidTokensSince>` using [`poem::EndpointExt::data`].
+#[cfg(feature = "poem")]
+#[poem::async_trait]
+pub trait ValidTokensSince: Send + Sync {
+    /// Returns `None` if there is no user with this id.
+    async fn valid_tokens_since(&self, user_id: Snowflake) -> Option<DateTime<Utc>>;
+}
+
+/// Extracts the verified [`Claims`] of the token in the `Authorization` header of a request, with
+/// or without the `Bearer` prefix. The token is verified like in [`verify_token`], using the
+/// [`SecurityConfiguration`] and the [`ValidTokensSince`] in the data of the request, which have
+/// to be added using [`poem::EndpointExt::data`].
+#[cfg(feature = "poem")]
+#[poem::async_trait]
+impl<'a> poem::FromRequest<'a> for Claims {
+    async fn from_request(
+        req: &'a poem::Request,
+        _body: &mut poem::RequestBody,
+    ) -> poem::Result<Self> {
+        let config = req.data::<SecurityConfiguration>().ok_or_else(|| {
+            poem::Error::from_string(
+                "No SecurityConfiguration has been added to the request data",
+                poem::http::StatusCode::INTERNAL_SERVER_ERROR,
+            )
+        })?;
+        let lookup = req
+            .data::<std::sync::Arc<dyn ValidTokensSince>>()
+            .ok_or_else(|| {
+                poem::Error::from_string(
+                    "No ValidTokensSince has been added to the request data",
+                    poem::http::StatusCode::INTERNAL_SERVER_ERROR,
+                )
+            })?;
+        let token = req
+            .headers()
+            .get(poem::http::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(TokenError::Missing)?;
+        let token = token.strip_prefix("Bearer ").unwrap_or(token);
+        let claims = decode_token(token, config)?;
+        let user_id = claims
+            .id
+            .parse::<u64>()
+            .map_err(|_| TokenError::UnknownUser)?;
+        let valid_tokens_since = lookup
+            .valid_tokens_since(Snowflake::from(user_id))
+            .await
+            .ok_or(TokenError::UnknownUser)?;
+        claims.check_issued_after(valid_tokens_since)?;
+        Ok(claims)
+    }
+}
+
+#[cfg(test)]
+mod jwt {
+    use super::*;
+
+    #[test]
+    fn encode_and_decode() {
+        let config = SecurityConfiguration::default();
+        let id = Snowflake::generate();
+        let claims = Claims::new("user@example.org", &id);
+        let token = encode_token(&claims, &config).unwrap();
+        assert_eq!(decode_token(&token, &config).unwrap(), claims);
+
+        let other_config = SecurityConfiguration::default();
+        assert!(matches!(
+            decode_token(&token, &other_config),
+            Err(TokenError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn configured_algorithm() {
+        let config = SecurityConfiguration {
+            jwt_algorithm: Algorithm::HS512,
+            ..Default::default()
+        };
+        let claims = Claims::new("user@example.org", &Snowflake::generate());
+        let token = encode_token(&claims, &config).unwrap();
+        assert_eq!(decode_token(&token, &config).unwrap(), claims);
+    }
+
+    #[test]
+    fn expired_and_outdated_tokens() {
+        let config = SecurityConfiguration::default();
+        let id = Snowflake::generate();
+        let claims = Claims::with_lifetime("user@example.org", &id, Duration::hours(-1));
+        let token = encode_token(&claims, &config).unwrap();
+        assert!(matches!(
+            decode_token(&token, &config),
+            Err(TokenError::Expired)
+        ));
+
+        let claims = Claims::new("user@example.org", &id);
+        let token = encode_token(&claims, &config).unwrap();
+        let password_changed = Utc::now() + Duration::minutes(1);
+        assert!(matches!(
+            verify_token(&token, &config, password_changed),
+            Err(TokenError::IssuedBeforePasswordChange)
+        ));
+    }
+}
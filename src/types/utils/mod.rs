@@ -1,8 +1,13 @@
-pub use regexes::*;
+pub use content::*;
+pub use image::*;
 pub use rights::Rights;
 pub use snowflake::Snowflake;
+pub use validation::*;
 
+mod content;
+mod image;
 pub mod jwt;
 mod regexes;
 mod rights;
 mod snowflake;
+mod validation;
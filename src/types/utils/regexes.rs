@@ -4,9 +4,17 @@ use regex::Regex;
 lazy_static! {
     static ref DOUBLE_WHITE_SPACE_RE: Regex = Regex::new(r"\s\s+").unwrap();
     static ref SPECIAL_CHAR: Regex = Regex::new(r"@#`:\r\n\t\f\v\p{C}").unwrap();
-    static ref CHANNEL_MENTION: Regex = Regex::new(r"<#(\d+)>").unwrap();
-    static ref USER_MENTION: Regex = Regex::new(r"<@!?(\d+)>").unwrap();
-    static ref ROLE_MENTION: Regex = Regex::new(r"<@&(\d+)>").unwrap();
-    static ref EVERYONE_MENTION: Regex = Regex::new(r"@everyone").unwrap();
-    static ref HERE_MENTION: Regex = Regex::new(r"@here").unwrap();
+    // The regexes of the content parser are anchored at the start of the text, so that a failed
+    // match at one position does not search the rest of the message.
+    pub(crate) static ref CHANNEL_MENTION: Regex = Regex::new(r"\A<#(\d+)>").unwrap();
+    pub(crate) static ref USER_MENTION: Regex = Regex::new(r"\A<@!?(\d+)>").unwrap();
+    pub(crate) static ref ROLE_MENTION: Regex = Regex::new(r"\A<@&(\d+)>").unwrap();
+    pub(crate) static ref EVERYONE_MENTION: Regex = Regex::new(r"\A@everyone").unwrap();
+    pub(crate) static ref HERE_MENTION: Regex = Regex::new(r"\A@here").unwrap();
+    pub(crate) static ref CUSTOM_EMOJI: Regex = Regex::new(r"\A<(a)?:(\w+):(\d+)>").unwrap();
+    pub(crate) static ref TIMESTAMP: Regex = Regex::new(r"\A<t:(-?\d+)(?::([tTdDfFR]))?>").unwrap();
+    pub(crate) static ref URL: Regex = Regex::new(r#"\Ahttps?://[^\s<]+[^<.,:;"')\]\s]"#).unwrap();
+    pub(crate) static ref NO_EMBED_URL: Regex = Regex::new(r"\A<(https?://[^\s<>]+)>").unwrap();
+    pub(crate) static ref MASKED_LINK: Regex =
+        Regex::new(r"\A\[([^\[\]]+)\]\(<?(https?://[^\s()<>]+)>?\)").unwrap();
 }
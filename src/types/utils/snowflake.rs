@@ -12,7 +12,7 @@ const EPOCH: i64 = 1420070400000;
 
 /// Unique identifier including a timestamp.
 /// See https://discord.com/developers/docs/reference#snowflakes
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "sqlx", derive(Type))]
 #[cfg_attr(feature = "sqlx", sqlx(transparent))]
 pub struct Snowflake(u64);
@@ -35,6 +35,13 @@ impl Snowflake {
         Utc.timestamp_millis_opt((self.0 >> 22) as i64 + EPOCH)
             .unwrap()
     }
+
+    /// The smallest snowflake created at `timestamp`, for use as a bound when filtering by date.
+    /// Timestamps before the epoch of 2015-01-01 result in the smallest possible snowflake.
+    pub fn from_timestamp(timestamp: DateTime<Utc>) -> Self {
+        let millis = (timestamp.timestamp_millis() - EPOCH).max(0);
+        Self((millis as u64) << 22)
+    }
 }
 
 impl Default for Snowflake {
@@ -43,6 +50,18 @@ impl Default for Snowflake {
     }
 }
 
+impl From<u64> for Snowflake {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+impl From<Snowflake> for u64 {
+    fn from(id: Snowflake) -> Self {
+        id.0
+    }
+}
+
 impl Display for Snowflake {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -103,5 +122,7 @@ mod test {
         let snow: Snowflake = serde_json::from_str("\"175928847299117063\"").unwrap();
         let timestamp = "2016-04-30 11:18:25.796Z".parse::<DateTime<Utc>>().unwrap();
         assert_eq!(snow.timestamp(), timestamp);
+        assert_eq!(Snowflake::from_timestamp(timestamp).timestamp(), timestamp);
+        assert!(Snowflake::from_timestamp(timestamp).0 <= snow.0);
     }
 }
@@ -0,0 +1,314 @@
+use crate::types::{LimitsConfiguration, ValidationError};
+
+/// The maximum length of the name of a channel or role. Not configurable on Spacebar.
+pub const MAX_NAME_LENGTH: u64 = 100;
+
+/// Checks a request body against the limits of an instance, so that invalid requests can be
+/// rejected before they are sent.
+pub trait Validate {
+    fn validate(&self, limits: &LimitsConfiguration) -> Result<(), ValidationError>;
+}
+
+/// Checks that `value` is between `min` and `max` characters long.
+pub(crate) fn check_length(
+    field: &'static str,
+    value: &str,
+    min: u64,
+    max: u64,
+) -> Result<(), ValidationError> {
+    let actual = value.chars().count() as u64;
+    if actual < min {
+        return Err(ValidationError::TooShort { field, min, actual });
+    }
+    if actual > max {
+        return Err(ValidationError::TooLong { field, max, actual });
+    }
+    Ok(())
+}
+
+/// Checks that `size` does not exceed `max` bytes.
+pub(crate) fn check_size(field: &'static str, size: u64, max: u64) -> Result<(), ValidationError> {
+    if size > max {
+        return Err(ValidationError::TooLarge {
+            field,
+            max,
+            actual: size,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod validation {
+    use chrono::{Duration, Utc};
+
+    use crate::types::{
+        ActionRow, Button, ButtonStyle, ChannelCreateSchema, Component, CreateChannelInviteSchema,
+        Embed, EmojiModifySchema, FileUpload, GuildScheduledEventCreateSchema,
+        GuildScheduledEventEntityType, GuildScheduledEventModifySchema, LimitsConfiguration,
+        MessageBulkDeleteSchema, MessageModifySchema, MessageSendSchema,
+        PrivateChannelCreateSchema, RegisterConfiguration, RegisterSchema, Snowflake,
+        StickerCreateSchema, TagCreateSchema, ThreadModifySchema, Validate, ValidationError,
+        WebhookCreateSchema, WebhookExecuteSchema,
+    };
+
+    #[test]
+    fn message_content_length() {
+        let mut limits = LimitsConfiguration::default();
+        limits.message.max_characters = 5;
+        let mut message = MessageSendSchema {
+            content: Some("Hello".to_string()),
+            ..Default::default()
+        };
+        assert!(message.validate(&limits).is_ok());
+        message.content = Some("Hello!".to_string());
+        assert_eq!(
+            message.validate(&limits),
+            Err(ValidationError::TooLong {
+                field: "content",
+                max: 5,
+                actual: 6
+            })
+        );
+    }
+
+    #[test]
+    fn channel_name_and_topic() {
+        let limits = LimitsConfiguration::default();
+        let mut channel = ChannelCreateSchema {
+            name: String::new(),
+            ..Default::default()
+        };
+        assert!(channel.validate(&limits).is_err());
+        channel.name = "general".to_string();
+        channel.topic = Some("a".repeat(limits.channel.max_topic as usize + 1));
+        assert!(channel.validate(&limits).is_err());
+    }
+
+    #[test]
+    fn registration_policies() {
+        let config = RegisterConfiguration::default();
+        let mut register = RegisterSchema {
+            username: "user".to_string(),
+            consent: true,
+            password: Some("PAssword12".to_string()),
+            date_of_birth: Some("2000-01-01".to_string()),
+            ..Default::default()
+        };
+        assert!(register.validate(&LimitsConfiguration::default()).is_ok());
+        assert!(register.validate_registration(&config).is_ok());
+
+        register.password = Some("password12".to_string());
+        assert!(register.validate_registration(&config).is_err());
+        register.password = Some("PAssword12".to_string());
+
+        register.date_of_birth = Some("2099-01-01".to_string());
+        assert!(register.validate_registration(&config).is_err());
+
+        register.consent = false;
+        assert!(register.validate(&LimitsConfiguration::default()).is_err());
+    }
+
+    #[test]
+    fn message_components() {
+        let limits = LimitsConfiguration::default();
+        let row = Component::ActionRow(ActionRow::new(vec![Component::Button(Button::new(
+            ButtonStyle::Primary,
+            "Ok",
+            "ok",
+        ))]));
+        let mut message = MessageSendSchema {
+            components: Some(vec![row.clone(); 5]),
+            ..Default::default()
+        };
+        assert!(message.validate(&limits).is_ok());
+        message.components = Some(vec![row.clone(); 6]);
+        assert!(message.validate(&limits).is_err());
+
+        let edit = MessageModifySchema {
+            components: Some(vec![row; 6]),
+            ..Default::default()
+        };
+        assert!(edit.validate(&limits).is_err());
+    }
+
+    #[test]
+    fn bulk_delete() {
+        let mut limits = LimitsConfiguration::default();
+        limits.message.max_bulk_delete = 3;
+        let mut bulk = MessageBulkDeleteSchema {
+            messages: vec![Snowflake::generate()],
+        };
+        assert!(bulk.validate(&limits).is_err());
+        bulk.messages.push(Snowflake::generate());
+        assert!(bulk.validate(&limits).is_ok());
+        bulk.messages.push(Snowflake::generate());
+        bulk.messages.push(Snowflake::generate());
+        assert!(bulk.validate(&limits).is_err());
+
+        let old: Snowflake = serde_json::from_str("\"175928847299117063\"").unwrap();
+        bulk.messages = vec![Snowflake::generate(), old];
+        assert!(bulk.validate(&limits).is_err());
+    }
+
+    #[test]
+    fn emoji_and_sticker_fields() {
+        let limits = LimitsConfiguration::default();
+        let mut emoji = EmojiModifySchema {
+            name: Some("party_parrot".to_string()),
+            ..Default::default()
+        };
+        assert!(emoji.validate(&limits).is_ok());
+        emoji.name = Some("party parrot".to_string());
+        assert!(emoji.validate(&limits).is_err());
+
+        let file = FileUpload::from_bytes("sticker.png", vec![0; 1024]);
+        let sticker = StickerCreateSchema::new("Wave", "wave", file).description("Waving");
+        assert!(sticker.validate(&limits).is_ok());
+        let file = FileUpload::from_bytes("sticker.jpg", vec![0; 1024]);
+        assert!(StickerCreateSchema::new("Wave", "wave", file)
+            .validate(&limits)
+            .is_err());
+        let file = FileUpload::from_bytes("sticker.png", vec![0; 1024 * 1024]);
+        assert!(StickerCreateSchema::new("Wave", "wave", file)
+            .validate(&limits)
+            .is_err());
+
+        let message = MessageSendSchema {
+            sticker_ids: Some(vec![Snowflake::generate(); 4]),
+            ..Default::default()
+        };
+        assert!(message.validate(&limits).is_err());
+    }
+
+    #[test]
+    fn scheduled_event_entities() {
+        let limits = LimitsConfiguration::default();
+        let start = Utc::now() + Duration::days(1);
+        let end = start + Duration::hours(2);
+        let external = GuildScheduledEventCreateSchema::external("Meetup", "Park", start, end);
+        assert!(external.validate(&limits).is_ok());
+        let mut missing_end = external.clone();
+        missing_end.scheduled_end_time = None;
+        assert_eq!(
+            missing_end.validate(&limits),
+            Err(ValidationError::Missing {
+                field: "scheduled_end_time"
+            })
+        );
+        let mut ends_early = external;
+        ends_early.scheduled_end_time = Some(start - Duration::hours(1));
+        assert!(ends_early.validate(&limits).is_err());
+
+        let voice = GuildScheduledEventCreateSchema::in_channel(
+            "Voice chat",
+            GuildScheduledEventEntityType::Voice,
+            Snowflake::generate(),
+            start,
+        );
+        assert!(voice.validate(&limits).is_ok());
+        let modify = GuildScheduledEventModifySchema {
+            entity_type: Some(GuildScheduledEventEntityType::External),
+            scheduled_end_time: Some(end),
+            ..Default::default()
+        };
+        assert_eq!(
+            modify.validate(&limits),
+            Err(ValidationError::Missing {
+                field: "entity_metadata.location"
+            })
+        );
+    }
+
+    #[test]
+    fn invite_age_and_uses() {
+        let limits = LimitsConfiguration::default();
+        let invite = CreateChannelInviteSchema::default()
+            .max_age(0)
+            .max_uses(100);
+        assert!(invite.validate(&limits).is_ok());
+        assert!(invite.clone().max_age(604801).validate(&limits).is_err());
+        assert!(invite.max_uses(101).validate(&limits).is_err());
+    }
+
+    #[test]
+    fn group_dm_recipients() {
+        let limits = LimitsConfiguration::default();
+        let mut group = PrivateChannelCreateSchema {
+            recipients: vec![Snowflake::generate(); 9],
+        };
+        assert!(group.validate(&limits).is_ok());
+        group.recipients.push(Snowflake::generate());
+        assert!(group.validate(&limits).is_err());
+    }
+
+    #[test]
+    fn webhook_names() {
+        let limits = LimitsConfiguration::default();
+        let mut webhook = WebhookCreateSchema {
+            name: "Announcements".to_string(),
+            ..Default::default()
+        };
+        assert!(webhook.validate(&limits).is_ok());
+        webhook.name = "Not Clyde".to_string();
+        assert!(webhook.validate(&limits).is_err());
+        webhook.name = String::new();
+        assert!(webhook.validate(&limits).is_err());
+
+        let message = WebhookExecuteSchema::content("Hello").username(&"a".repeat(81));
+        assert!(message.validate(&limits).is_err());
+    }
+
+    #[test]
+    fn forum_tag_names() {
+        let limits = LimitsConfiguration::default();
+        let mut tag = TagCreateSchema {
+            name: "Question".to_string(),
+            ..Default::default()
+        };
+        assert!(tag.validate(&limits).is_ok());
+        tag.name = String::new();
+        assert!(tag.validate(&limits).is_err());
+        tag.name = "a".repeat(21);
+        assert!(tag.validate(&limits).is_err());
+    }
+
+    #[test]
+    fn thread_auto_archive_duration() {
+        let limits = LimitsConfiguration::default();
+        assert!(ThreadModifySchema::auto_archive_duration(1440)
+            .validate(&limits)
+            .is_ok());
+        assert!(ThreadModifySchema::auto_archive_duration(30)
+            .validate(&limits)
+            .is_err());
+    }
+
+    #[test]
+    fn embed_limits() {
+        let limits = LimitsConfiguration::default();
+        let embed = Embed::new().title("Title").field("Name", "Value", false);
+        assert!(vec![embed.clone()].validate(&limits).is_ok());
+        assert!(embed
+            .clone()
+            .title(&"a".repeat(257))
+            .validate(&limits)
+            .is_err());
+        assert!(embed
+            .clone()
+            .field("Name", "", true)
+            .validate(&limits)
+            .is_err());
+
+        let long = Embed::new().description(&"a".repeat(4000));
+        assert!(long.validate(&limits).is_ok());
+        assert_eq!(
+            vec![long.clone(), long].validate(&limits),
+            Err(ValidationError::TooLong {
+                field: "embeds",
+                max: 6000,
+                actual: 8000
+            })
+        );
+    }
+}
@@ -1,4 +1,10 @@
-use chorus::types::RegisterSchema;
+use std::sync::Arc;
+
+use chorus::api::auth::CredentialProvider;
+use chorus::errors::ChorusLibError;
+use chorus::instance::SavedSession;
+use chorus::types::{LoginSchema, RegisterSchema, Snowflake};
+use futures_util::future::BoxFuture;
 
 mod common;
 
@@ -14,3 +20,118 @@ async fn test_registration() {
     bundle.instance.register_account(&reg).await.unwrap();
     common::teardown(bundle).await;
 }
+
+#[tokio::test]
+async fn test_restore_session() {
+    let mut bundle = common::setup().await;
+    let session = bundle.user.save_session().await;
+    let serialized = serde_json::to_string(&session).unwrap();
+    let session: SavedSession = serde_json::from_str(&serialized).unwrap();
+    let restored = bundle.instance.restore_account(&session).await.unwrap();
+    assert_eq!(restored.object, bundle.user.object);
+    assert_eq!(restored.token, bundle.user.token);
+
+    let mismatched = SavedSession {
+        user_id: Snowflake::generate(),
+        ..session
+    };
+    assert!(matches!(
+        bundle.instance.restore_account(&mismatched).await,
+        Err(ChorusLibError::InvalidArgumentsError { .. })
+    ));
+    common::teardown(bundle).await;
+}
+
+#[tokio::test]
+async fn test_login_totp_invalid_ticket() {
+    let mut bundle = common::setup().await;
+    let result = bundle
+        .instance
+        .login_totp("not a valid ticket", "123456")
+        .await;
+    assert!(result.is_err());
+    common::teardown(bundle).await;
+}
+
+#[tokio::test]
+async fn test_mfa_wrong_password_or_code() {
+    let mut bundle = common::setup().await;
+    let result = bundle
+        .user
+        .get_backup_codes("not the password", false)
+        .await;
+    assert!(result.is_err());
+    let result = bundle.user.disable_totp("123456").await;
+    assert!(result.is_err());
+    common::teardown(bundle).await;
+}
+
+#[derive(Debug)]
+struct StaticCredentials(LoginSchema);
+
+impl CredentialProvider for StaticCredentials {
+    fn credentials(&self) -> BoxFuture<'_, Option<LoginSchema>> {
+        Box::pin(async { Some(self.0.clone()) })
+    }
+}
+
+#[tokio::test]
+async fn test_token_renewal_and_logout() {
+    let mut bundle = common::setup().await;
+    let reg = RegisterSchema {
+        username: "integrationtestuser5".into(),
+        password: Some("integrationtestpassword".into()),
+        email: Some("integrationtestuser5@example.org".into()),
+        date_of_birth: Some("2000-01-01".to_string()),
+        consent: true,
+        ..Default::default()
+    };
+    let mut user = bundle.instance.register_account(&reg).await.unwrap();
+    let login = LoginSchema {
+        login: "integrationtestuser5@example.org".into(),
+        password: Some("integrationtestpassword".into()),
+        undelete: None,
+        captcha_key: None,
+        login_source: None,
+        gift_code_sku_id: None,
+    };
+    let second_session = bundle.instance.login_account(&login).await.unwrap();
+    second_session.logout().await.unwrap();
+
+    user.set_credential_provider(Some(Arc::new(StaticCredentials(login))));
+    user.set_token("expired".to_string());
+    user.get_relationships().await.unwrap();
+    assert_ne!(user.token(), "expired");
+
+    user.delete().await.unwrap();
+    common::teardown(bundle).await;
+}
+
+#[tokio::test]
+async fn test_change_password() {
+    let mut bundle = common::setup().await;
+    let reg = RegisterSchema {
+        username: "integrationtestuser6".into(),
+        password: Some("integrationtestpassword".into()),
+        email: Some("integrationtestuser6@example.org".into()),
+        date_of_birth: Some("2000-01-01".to_string()),
+        consent: true,
+        ..Default::default()
+    };
+    let mut user = bundle.instance.register_account(&reg).await.unwrap();
+    let old_token = user.token();
+    let old_object = user.object.clone();
+    assert!(user
+        .change_password("not the password", "anotherpassword")
+        .await
+        .is_err());
+    let changed = user
+        .change_password("integrationtestpassword", "anotherpassword")
+        .await
+        .unwrap();
+    assert_ne!(user.token(), old_token);
+    assert_eq!(changed.username, old_object.username);
+    assert_eq!(changed.bio, old_object.bio);
+    user.delete().await.unwrap();
+    common::teardown(bundle).await;
+}
@@ -18,6 +18,14 @@ async fn get_channel() {
     common::teardown(bundle).await
 }
 
+#[tokio::test]
+async fn get_unknown_channel() {
+    let mut bundle = common::setup().await;
+    let result = Channel::get(&mut bundle.user, Snowflake::generate()).await;
+    assert!(result.is_err());
+    common::teardown(bundle).await
+}
+
 #[tokio::test]
 async fn delete_channel() {
     let mut bundle = common::setup().await;
@@ -136,3 +144,248 @@ async fn get_channel_messages() {
 
     common::teardown(bundle).await
 }
+
+#[tokio::test]
+async fn create_join_and_archive_thread() {
+    let mut bundle = common::setup().await;
+    let channel_id = bundle.channel.id;
+    let mut message = types::MessageSendSchema {
+        content: Some("Thread starter".to_string()),
+        ..Default::default()
+    };
+    let message = bundle
+        .user
+        .send_message(&mut message, channel_id, None)
+        .await
+        .unwrap();
+    let schema = types::ThreadFromMessageCreateSchema {
+        name: "A thread".to_string(),
+        auto_archive_duration: Some(60),
+        ..Default::default()
+    };
+    let thread =
+        Channel::create_thread_from_message(&mut bundle.user, channel_id, message.id, &schema)
+            .await
+            .unwrap();
+    assert_eq!(thread.name, Some("A thread".to_string()));
+
+    Channel::join_thread(&mut bundle.user, thread.id)
+        .await
+        .unwrap();
+    let members = Channel::thread_members(&mut bundle.user, thread.id)
+        .await
+        .unwrap();
+    assert!(!members.is_empty());
+    let active = types::Guild::active_threads(&mut bundle.user, bundle.guild.id)
+        .await
+        .unwrap();
+    assert!(active.threads.iter().any(|active| active.id == thread.id));
+
+    let archived = Channel::modify_thread(
+        &mut bundle.user,
+        thread.id,
+        &types::ThreadModifySchema::archived(true),
+    )
+    .await
+    .unwrap();
+    assert!(archived.thread_metadata.unwrap().archived);
+    Channel::leave_thread(&mut bundle.user, thread.id)
+        .await
+        .unwrap();
+    common::teardown(bundle).await
+}
+
+#[tokio::test]
+async fn create_forum_post() {
+    let mut bundle = common::setup().await;
+    let forum_schema = types::ChannelCreateSchema {
+        name: "forum".to_string(),
+        channel_type: Some(types::ChannelType::GuildForum as u8),
+        ..Default::default()
+    };
+    let forum = Channel::create(&mut bundle.user, bundle.guild.id, forum_schema)
+        .await
+        .unwrap();
+    let schema = types::ForumPostCreateSchema {
+        name: "A post".to_string(),
+        message: MessageSendSchema {
+            content: Some("The first message of the post".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let post = Channel::create_forum_post(&mut bundle.user, forum.id, &schema)
+        .await
+        .unwrap();
+    assert_eq!(post.thread.parent_id, Some(forum.id));
+
+    let tag_schema = types::TagCreateSchema {
+        name: "Question".to_string(),
+        ..Default::default()
+    };
+    let forum = Channel::create_tag(&mut bundle.user, forum.id, &tag_schema)
+        .await
+        .unwrap();
+    let tags = forum.available_tags.unwrap();
+    let tag = tags.iter().find(|tag| tag.name == "Question").unwrap();
+    assert!(!tag.moderated);
+    let tag_id = tag.id;
+    Channel::set_forum_post_tags(&mut bundle.user, post.thread.id, vec![tag_id])
+        .await
+        .unwrap();
+
+    let tag_schema = types::TagCreateSchema {
+        name: "Answered".to_string(),
+        moderated: Some(true),
+        ..Default::default()
+    };
+    let forum = Channel::modify_tag(&mut bundle.user, forum.id, tag_id, &tag_schema)
+        .await
+        .unwrap();
+    let tags = forum.available_tags.unwrap();
+    let tag = tags.iter().find(|tag| tag.id == tag_id).unwrap();
+    assert_eq!(tag.name, "Answered");
+    assert!(tag.moderated);
+
+    let forum = Channel::delete_tag(&mut bundle.user, forum.id, tag_id)
+        .await
+        .unwrap();
+    assert!(!forum
+        .available_tags
+        .unwrap_or_default()
+        .iter()
+        .any(|tag| tag.id == tag_id));
+    Channel::set_forum_post_tags(&mut bundle.user, post.thread.id, Vec::new())
+        .await
+        .unwrap();
+    common::teardown(bundle).await
+}
+
+#[tokio::test]
+async fn direct_messages_and_group_dms() {
+    let mut bundle = common::setup().await;
+    let register_schema = types::RegisterSchema {
+        username: "integrationtestuser2".to_string(),
+        consent: true,
+        date_of_birth: Some("2000-01-01".to_string()),
+        ..Default::default()
+    };
+    let mut other_user = bundle
+        .instance
+        .register_account(&register_schema)
+        .await
+        .unwrap();
+    let other_id = other_user.object.id;
+
+    let dm = Channel::create_dm(&mut bundle.user, other_id)
+        .await
+        .unwrap();
+    assert_eq!(dm.channel_type, types::ChannelType::Dm);
+    let mut message = MessageSendSchema {
+        content: Some("Hello in private".to_string()),
+        ..Default::default()
+    };
+    bundle
+        .user
+        .send_message(&mut message, dm.id, None)
+        .await
+        .unwrap();
+    let private_channels = Channel::private_channels(&mut other_user).await.unwrap();
+    assert!(private_channels.iter().any(|channel| channel.id == dm.id));
+    Channel::close_private_channel(&mut bundle.user, dm.id)
+        .await
+        .unwrap();
+
+    assert!(Channel::create_group_dm(&mut bundle.user, vec![other_id])
+        .await
+        .is_err());
+    let group = Channel::create_group_dm(&mut bundle.user, vec![])
+        .await
+        .unwrap();
+    assert_eq!(group.channel_type, types::ChannelType::GroupDm);
+    Channel::add_recipient(&mut bundle.user, group.id, other_id)
+        .await
+        .unwrap();
+    let schema = types::GroupDmModifySchema {
+        name: Some("Test group".to_string()),
+        ..Default::default()
+    };
+    let group = Channel::modify_group_dm(&mut bundle.user, group.id, &schema)
+        .await
+        .unwrap();
+    assert_eq!(group.name.as_deref(), Some("Test group"));
+    Channel::remove_recipient(&mut bundle.user, group.id, other_id)
+        .await
+        .unwrap();
+    Channel::close_private_channel(&mut bundle.user, group.id)
+        .await
+        .unwrap();
+
+    other_user.delete().await.unwrap();
+    common::teardown(bundle).await
+}
+
+#[tokio::test]
+async fn webhooks() {
+    let mut bundle = common::setup().await;
+    let channel_id = bundle.channel.id;
+    let schema = types::WebhookCreateSchema {
+        name: "Test webhook".to_string(),
+        ..Default::default()
+    };
+    let webhook = types::Webhook::create(&mut bundle.user, channel_id, &schema)
+        .await
+        .unwrap();
+    let token = webhook.token.clone().unwrap();
+    let webhooks = types::Webhook::channel_webhooks(&mut bundle.user, channel_id)
+        .await
+        .unwrap();
+    assert!(webhooks.iter().any(|other| other.id == webhook.id));
+
+    let mut message = types::WebhookExecuteSchema::content("Hello from a webhook").username("Bot");
+    let message = bundle
+        .instance
+        .execute_webhook(
+            webhook.id,
+            &token,
+            &mut message,
+            None,
+            &types::WebhookExecuteQuery::wait(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    let schema = types::MessageModifySchema {
+        content: Some("Edited".to_string()),
+        ..Default::default()
+    };
+    let edited = bundle
+        .instance
+        .edit_webhook_message(webhook.id, &token, message.id, &schema, None)
+        .await
+        .unwrap();
+    assert_eq!(edited.content.as_deref(), Some("Edited"));
+    bundle
+        .instance
+        .delete_webhook_message(webhook.id, &token, message.id, None)
+        .await
+        .unwrap();
+
+    let mut empty = types::WebhookExecuteSchema::default();
+    assert!(bundle
+        .instance
+        .execute_webhook(
+            webhook.id,
+            &token,
+            &mut empty,
+            None,
+            &types::WebhookExecuteQuery::wait()
+        )
+        .await
+        .is_err());
+
+    types::Webhook::delete(&mut bundle.user, webhook.id)
+        .await
+        .unwrap();
+    common::teardown(bundle).await
+}
@@ -1,4 +1,11 @@
-use chorus::types::{Guild, GuildCreateSchema};
+use chorus::errors::ChorusLibError;
+use chorus::types::{
+    Channel, CreateChannelInviteSchema, Emoji, EmojiCreateSchema, EmojiModifySchema, FileUpload,
+    Guild, GuildCreateSchema, GuildInvite, GuildScheduledEvent, GuildScheduledEventCreateSchema,
+    GuildScheduledEventStatus, ImageData, MessageSendSchema, RegisterSchema, Sticker,
+    StickerCreateSchema,
+};
+use chrono::{Duration, Utc};
 
 mod common;
 
@@ -33,3 +40,142 @@ async fn get_channels() {
     );
     common::teardown(bundle).await;
 }
+
+const PNG: &str = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+#[tokio::test]
+async fn guild_emojis_and_stickers() {
+    let mut bundle = common::setup().await;
+    let guild_id = bundle.guild.id;
+
+    let emoji_schema = EmojiCreateSchema {
+        name: "test_emoji".to_string(),
+        image: PNG.parse::<ImageData>().unwrap(),
+        roles: None,
+    };
+    let emoji = Emoji::create(&mut bundle.user, guild_id, &emoji_schema)
+        .await
+        .unwrap();
+    let emoji_id = emoji.id.unwrap();
+    let modify_schema = EmojiModifySchema {
+        name: Some("renamed_emoji".to_string()),
+        ..Default::default()
+    };
+    let emoji = Emoji::modify(&mut bundle.user, guild_id, emoji_id, &modify_schema)
+        .await
+        .unwrap();
+    assert_eq!(emoji.name.as_deref(), Some("renamed_emoji"));
+    let emojis = Emoji::get_all(&mut bundle.user, guild_id).await.unwrap();
+    assert!(emojis.iter().any(|emoji| emoji.id == Some(emoji_id)));
+    Emoji::delete(&mut bundle.user, guild_id, emoji_id)
+        .await
+        .unwrap();
+
+    let image = PNG.parse::<ImageData>().unwrap();
+    let file = FileUpload::from_bytes("sticker.png", image.data().to_vec());
+    let sticker_schema = StickerCreateSchema::new("Test sticker", "wave", file);
+    let sticker = Sticker::create(&mut bundle.user, guild_id, sticker_schema)
+        .await
+        .unwrap();
+    let mut message = MessageSendSchema {
+        sticker_ids: Some(vec![sticker.id]),
+        ..Default::default()
+    };
+    let message = bundle
+        .user
+        .send_message(&mut message, bundle.channel.id, None)
+        .await
+        .unwrap();
+    assert_eq!(message.sticker_items.unwrap()[0].id, sticker.id);
+    Sticker::delete(&mut bundle.user, guild_id, sticker.id)
+        .await
+        .unwrap();
+    common::teardown(bundle).await
+}
+
+#[tokio::test]
+async fn guild_scheduled_events() {
+    let mut bundle = common::setup().await;
+    let guild_id = bundle.guild.id;
+    let start = Utc::now() + Duration::days(1);
+    let schema = GuildScheduledEventCreateSchema::external(
+        "Test event",
+        "Somewhere",
+        start,
+        start + Duration::hours(1),
+    )
+    .description("An event created by a test");
+    let event = GuildScheduledEvent::create(&mut bundle.user, guild_id, &schema)
+        .await
+        .unwrap();
+    assert_eq!(event.status, GuildScheduledEventStatus::Scheduled);
+    let events = GuildScheduledEvent::get_all(&mut bundle.user, guild_id, true)
+        .await
+        .unwrap();
+    assert!(events.iter().any(|other| other.id == event.id));
+
+    let event = event.cancel(&mut bundle.user).await.unwrap();
+    assert_eq!(event.status, GuildScheduledEventStatus::Canceled);
+    assert!(event.start(&mut bundle.user).await.is_err());
+    GuildScheduledEvent::delete(&mut bundle.user, guild_id, event.id)
+        .await
+        .unwrap();
+    common::teardown(bundle).await
+}
+
+#[tokio::test]
+async fn guild_invites_join_and_leave() {
+    let mut bundle = common::setup().await;
+    let register_schema = RegisterSchema {
+        username: "integrationtestuser2".to_string(),
+        consent: true,
+        date_of_birth: Some("2000-01-01".to_string()),
+        ..Default::default()
+    };
+    let mut other_user = bundle
+        .instance
+        .register_account(&register_schema)
+        .await
+        .unwrap();
+
+    let schema = CreateChannelInviteSchema::default()
+        .max_age(3600)
+        .max_uses(5)
+        .unique(true);
+    let invite = Channel::create_invite(&mut bundle.user, bundle.channel.id, &schema)
+        .await
+        .unwrap();
+    let invites = Guild::invites(&mut bundle.user, bundle.guild.id)
+        .await
+        .unwrap();
+    assert!(invites.iter().any(|other| other.code == invite.code));
+
+    let resolved = GuildInvite::get(&mut other_user, &invite.code, true)
+        .await
+        .unwrap();
+    assert_eq!(resolved.guild_id, bundle.guild.id);
+    GuildInvite::accept(&mut other_user, &invite.code)
+        .await
+        .unwrap();
+    Guild::leave(&mut other_user, bundle.guild.id)
+        .await
+        .unwrap();
+
+    GuildInvite::delete(&mut bundle.user, &invite.code)
+        .await
+        .unwrap();
+    let invites = Channel::invites(&mut bundle.user, bundle.channel.id)
+        .await
+        .unwrap();
+    assert!(invites.iter().all(|other| other.code != invite.code));
+    assert!(matches!(
+        GuildInvite::get(&mut other_user, &invite.code, false).await,
+        Err(ChorusLibError::ReceivedErrorCodeError { .. })
+    ));
+    assert!(matches!(
+        GuildInvite::accept(&mut other_user, &invite.code).await,
+        Err(ChorusLibError::ReceivedErrorCodeError { .. })
+    ));
+    other_user.delete().await.unwrap();
+    common::teardown(bundle).await
+}
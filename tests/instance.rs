@@ -10,3 +10,16 @@ async fn generate_general_configuration_schema() {
         .unwrap();
     common::teardown(bundle).await;
 }
+
+#[tokio::test]
+async fn discover_instance_domains() {
+    let bundle = common::setup().await;
+    let domains = chorus::instance::Instance::instance_domains(&bundle.urls.api)
+        .await
+        .unwrap();
+    assert!(!domains.cdn.is_empty());
+    assert!(chorus::instance::Instance::discover_urls("http://[::1")
+        .await
+        .is_err());
+    common::teardown(bundle).await;
+}
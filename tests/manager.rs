@@ -0,0 +1,55 @@
+use std::rc::Rc;
+
+use chorus::errors::ChorusLibError;
+use chorus::manager::ClientManager;
+use chorus::types::RegisterSchema;
+
+mod common;
+
+#[tokio::test]
+async fn accounts_share_instance() {
+    let bundle = common::setup().await;
+    let mut manager = ClientManager::new();
+    manager.add_instance(bundle.urls.clone()).await.unwrap();
+
+    let mut keys = Vec::new();
+    for username in ["integrationtestuser3", "integrationtestuser4"] {
+        let register_schema = RegisterSchema {
+            username: username.to_string(),
+            consent: true,
+            date_of_birth: Some("2000-01-01".to_string()),
+            ..Default::default()
+        };
+        keys.push(
+            manager
+                .register(&bundle.urls.api, &register_schema)
+                .await
+                .unwrap(),
+        );
+    }
+
+    let first = manager.account(&keys[0]).unwrap();
+    let second = manager.account(&keys[1]).unwrap();
+    assert!(Rc::ptr_eq(&first.user.belongs_to, &second.user.belongs_to));
+    assert_eq!(manager.accounts_on(&bundle.urls.api).count(), 2);
+
+    let invalid_url = "http://[invalid";
+    assert!(manager.instance(invalid_url).is_none());
+    assert_eq!(manager.accounts_on(invalid_url).count(), 0);
+    let register_schema = RegisterSchema {
+        username: "integrationtestuser5".to_string(),
+        consent: true,
+        date_of_birth: Some("2000-01-01".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        manager.register(invalid_url, &register_schema).await,
+        Err(ChorusLibError::InvalidUrlError { .. })
+    ));
+
+    for key in keys {
+        let account = manager.remove_account(&key).unwrap();
+        account.user.delete().await.unwrap();
+    }
+    common::teardown(bundle).await
+}
@@ -58,9 +58,186 @@ async fn send_message_attachment() {
         .send_message(
             &mut message,
             bundle.channel.id,
-            Some(vec![attachment.clone()]),
+            Some(vec![attachment.clone().into()]),
         )
         .await
         .unwrap();
     common::teardown(bundle).await
 }
+
+#[tokio::test]
+async fn edit_pin_and_delete_messages() {
+    let mut bundle = common::setup().await;
+    let channel_id = bundle.channel.id;
+    let mut schema = types::MessageSendSchema {
+        content: Some("First".to_string()),
+        ..Default::default()
+    };
+    let first = bundle
+        .user
+        .send_message(&mut schema, channel_id, None)
+        .await
+        .unwrap();
+
+    let modify = types::MessageModifySchema {
+        content: Some("Edited".to_string()),
+        ..Default::default()
+    };
+    let edited = bundle
+        .user
+        .edit_message(channel_id, first.id, &modify)
+        .await
+        .unwrap();
+    assert_eq!(edited.content, Some("Edited".to_string()));
+
+    bundle.user.pin_message(channel_id, first.id).await.unwrap();
+    let pinned = bundle.user.get_pinned_messages(channel_id).await.unwrap();
+    assert!(pinned.iter().any(|message| message.id == first.id));
+    bundle
+        .user
+        .unpin_message(channel_id, first.id)
+        .await
+        .unwrap();
+    let pinned = bundle.user.get_pinned_messages(channel_id).await.unwrap();
+    assert!(!pinned.iter().any(|message| message.id == first.id));
+
+    let mut messages = Vec::new();
+    for content in ["Second", "Third"] {
+        let mut schema = types::MessageSendSchema {
+            content: Some(content.to_string()),
+            ..Default::default()
+        };
+        let message = bundle
+            .user
+            .send_message(&mut schema, channel_id, None)
+            .await
+            .unwrap();
+        messages.push(message.id);
+    }
+    assert!(bundle
+        .user
+        .bulk_delete_messages(channel_id, vec![first.id])
+        .await
+        .is_err());
+    bundle
+        .user
+        .bulk_delete_messages(channel_id, messages)
+        .await
+        .unwrap();
+    bundle
+        .user
+        .delete_message(channel_id, first.id)
+        .await
+        .unwrap();
+    common::teardown(bundle).await
+}
+
+#[tokio::test]
+async fn search_messages() {
+    let mut bundle = common::setup().await;
+    let mut schema = types::MessageSendSchema {
+        content: Some("Searching for polyphony".to_string()),
+        ..Default::default()
+    };
+    let message = bundle
+        .user
+        .send_message(&mut schema, bundle.channel.id, None)
+        .await
+        .unwrap();
+    let query = types::MessageSearchQuery::new()
+        .content("polyphony")
+        .channel(bundle.channel.id);
+    let response = types::Guild::search_messages(&mut bundle.user, bundle.guild.id, &query)
+        .await
+        .unwrap();
+    assert!(response.hits().any(|hit| hit.id == message.id));
+    common::teardown(bundle).await
+}
+
+#[tokio::test]
+async fn send_message_embed() {
+    let mut bundle = common::setup().await;
+    let embed = types::Embed::new()
+        .title("An embed")
+        .description("With a description")
+        .color(0x00FF00)
+        .field("A field", "With a value", false)
+        .footer(types::EmbedFooter::new("A footer"));
+    let mut message = types::MessageSendSchema {
+        embeds: Some(vec![embed]),
+        ..Default::default()
+    };
+    let message = bundle
+        .user
+        .send_message(&mut message, bundle.channel.id, None)
+        .await
+        .unwrap();
+    assert_eq!(message.embeds[0].title, Some("An embed".to_string()));
+    common::teardown(bundle).await
+}
+
+#[tokio::test]
+async fn send_message_default_allowed_mentions() {
+    let mut bundle = common::setup().await;
+    bundle
+        .user
+        .belongs_to
+        .borrow_mut()
+        .set_default_allowed_mentions(Some(types::AllowedMention::none()));
+    let mut message = types::MessageSendSchema {
+        content: Some(format!("@everyone <@{}>", bundle.user.object.id)),
+        ..Default::default()
+    };
+    let sent = bundle
+        .user
+        .send_message(&mut message, bundle.channel.id, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        message.allowed_mentions,
+        Some(types::AllowedMention::none())
+    );
+    assert!(!sent.mention_everyone);
+    assert!(sent.mentions.unwrap_or_default().is_empty());
+    assert!(sent.mention_roles.is_empty());
+    common::teardown(bundle).await
+}
+
+#[tokio::test]
+async fn send_message_file_upload() {
+    let mut bundle = common::setup().await;
+    let file = types::FileUpload::from_path("./README.md")
+        .await
+        .unwrap()
+        .description("The readme")
+        .spoiler(true);
+    assert_eq!(file.mime_type(), "text/markdown");
+    let mut message = types::MessageSendSchema {
+        content: Some("A file".to_string()),
+        ..Default::default()
+    };
+    let message = bundle
+        .user
+        .send_message(&mut message, bundle.channel.id, Some(vec![file]))
+        .await
+        .unwrap();
+    assert_eq!(message.attachments[0].filename, "SPOILER_README.md");
+
+    let mut limits = bundle
+        .user
+        .belongs_to
+        .borrow()
+        .limits_information
+        .clone()
+        .unwrap_or_default();
+    limits.message.max_attachment_size = 1;
+    bundle.user.belongs_to.borrow_mut().limits_information = Some(limits);
+    let file = types::FileUpload::from_bytes("large.txt", vec![0; 2]);
+    let mut message = types::MessageSendSchema::default();
+    assert!(bundle
+        .user
+        .send_message(&mut message, bundle.channel.id, Some(vec![file]))
+        .await
+        .is_err());
+    common::teardown(bundle).await
+}